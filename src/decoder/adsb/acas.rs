@@ -1,3 +1,6 @@
+use crate::decoder::{altitude_code, bds, message_type, range_value};
+use std::fmt::{self, Display};
+
 pub(crate) fn threat_encounter(message: &[u32]) -> Option<char> {
     let multiple_threats = message[14] & 1 == 1;
     let single_threat = (message[10] >> 3) & 1 == 1;
@@ -10,6 +13,283 @@ pub(crate) fn threat_encounter(message: &[u32]) -> Option<char> {
     }
 }
 
+/// Resolution advisory report of DF16 (VDS 3,0), DF20/21 (BDS 3,0) or DF17/18 (TC 28 subtype 2) message.
+///
+/// # Examples
+///
+/// ```
+/// use squitterator::decoder::{message, resolution_advisory};
+/// if let Some(message) = message("80E1971830E200052C6B44FBE187") {
+///     let ra = resolution_advisory(&message, 16).unwrap();
+///     assert_eq!(ra.to_string(), "DESCEND,,0,0,4B1AD1");
+/// }
+/// ```
+pub fn resolution_advisory(message: &[u32], df: u32) -> Option<ResolutionAdvisory> {
+    match df {
+        16 => ResolutionAdvisory::from_message(message),
+        17 | 18 if message_type(message) == (28, 2) => ResolutionAdvisory::from_fields(message),
+        20 | 21 if bds(message) == (3, 0) => ResolutionAdvisory::from_message(message),
+        _ => None,
+    }
+}
+
+/// Active resolution advisories (ARA) field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Ara {
+    /// One threat, or several threats requiring the same sense.
+    Single {
+        corrective: bool,
+        downward: bool,
+        increased_rate: bool,
+        sense_reversal: bool,
+        crossing: bool,
+        positive: bool,
+    },
+    /// Several threats requiring different senses.
+    Multiple {
+        upward_correction: bool,
+        positive_climb: bool,
+        downward_correction: bool,
+        positive_descend: bool,
+        crossing: bool,
+        sense_reversal: bool,
+    },
+}
+
+impl Display for Ara {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let words = match *self {
+            Ara::Single {
+                corrective,
+                downward,
+                increased_rate,
+                sense_reversal,
+                crossing,
+                positive,
+            } => [
+                (sense_reversal, "REVERSE"),
+                (increased_rate, "INCREASE"),
+                (crossing, "CROSSING"),
+                (positive && !downward, "CLIMB"),
+                (positive && downward, "DESCEND"),
+                (!positive && downward, "LIMIT CLIMB"),
+                (!positive && !downward, "LIMIT DESCENT"),
+                (!corrective, "PREVENTIVE"),
+            ]
+            .iter()
+            .filter(|(set, _)| *set)
+            .map(|(_, word)| *word)
+            .collect::<Vec<_>>(),
+            Ara::Multiple {
+                upward_correction,
+                positive_climb,
+                downward_correction,
+                positive_descend,
+                crossing,
+                sense_reversal,
+            } => [
+                (sense_reversal, "REVERSE"),
+                (crossing, "CROSSING"),
+                (upward_correction, "UP"),
+                (positive_climb, "CLIMB"),
+                (downward_correction, "DOWN"),
+                (positive_descend, "DESCEND"),
+            ]
+            .iter()
+            .filter(|(set, _)| *set)
+            .map(|(_, word)| *word)
+            .collect::<Vec<_>>(),
+        };
+        write!(f, "{}", words.join(" "))
+    }
+}
+
+/// Resolution advisory complements (RAC) field, the vertical and turn restrictions
+/// coordinated with the threats.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rac {
+    pub do_not_pass_below: bool,
+    pub do_not_pass_above: bool,
+    pub do_not_turn_left: bool,
+    pub do_not_turn_right: bool,
+}
+
+impl Display for Rac {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let words = [
+            (self.do_not_pass_below, "NO BELOW"),
+            (self.do_not_pass_above, "NO ABOVE"),
+            (self.do_not_turn_left, "NO LEFT"),
+            (self.do_not_turn_right, "NO RIGHT"),
+        ]
+        .iter()
+        .filter(|(set, _)| *set)
+        .map(|(_, word)| *word)
+        .collect::<Vec<_>>();
+        write!(f, "{}", words.join(" "))
+    }
+}
+
+/// Identity of the threat of the resolution advisory.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ThreatIdentity {
+    /// Mode S address of the threat (TTI 1).
+    Address(u32),
+    /// Altitude, range (NM) and bearing (degrees) of a threat without Mode S address (TTI 2).
+    Position {
+        altitude: Option<i32>,
+        range: Option<f64>,
+        bearing: Option<u32>,
+    },
+}
+
+/// ACAS resolution advisory report (VDS/BDS 3,0) of the DF16 MV or the DF20/21 MB field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResolutionAdvisory {
+    pub ara: u32,
+    pub rac: u32,
+    pub terminated: bool,
+    pub multiple_threats: bool,
+    pub threat: Option<ThreatIdentity>,
+}
+
+impl ResolutionAdvisory {
+    /// Decodes the report from the 56 bits starting at bit 33, `None` unless it is VDS 3,0.
+    pub fn from_message(message: &[u32]) -> Option<Self> {
+        if range_value(message, 33, 40)? != 0x30 {
+            return None;
+        }
+        Self::from_fields(message)
+    }
+
+    /// Decodes the fields following the first byte, shared by VDS 3,0 and TC 28 subtype 2.
+    fn from_fields(message: &[u32]) -> Option<Self> {
+        Some(ResolutionAdvisory {
+            ara: range_value(message, 41, 54)?,
+            rac: range_value(message, 55, 58)?,
+            terminated: range_value(message, 59, 59)? == 1,
+            multiple_threats: range_value(message, 60, 60)? == 1,
+            threat: threat_identity(message),
+        })
+    }
+
+    /// At least one resolution advisory is active.
+    pub fn is_active(&self) -> bool {
+        self.ara >> 13 == 1 || self.multiple_threats
+    }
+
+    /// Interpretation of the ARA field, `None` when no advisory is active.
+    pub fn advisory(&self) -> Option<Ara> {
+        let bit = |n: u32| (self.ara >> (13 - n)) & 1 == 1;
+        match (bit(0), self.multiple_threats) {
+            (true, _) => Some(Ara::Single {
+                corrective: bit(1),
+                downward: bit(2),
+                increased_rate: bit(3),
+                sense_reversal: bit(4),
+                crossing: bit(5),
+                positive: bit(6),
+            }),
+            (false, true) => Some(Ara::Multiple {
+                upward_correction: bit(1),
+                positive_climb: bit(2),
+                downward_correction: bit(3),
+                positive_descend: bit(4),
+                crossing: bit(5),
+                sense_reversal: bit(6),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Interpretation of the RAC field.
+    pub fn complement(&self) -> Rac {
+        Rac {
+            do_not_pass_below: self.rac & 0b1000 != 0,
+            do_not_pass_above: self.rac & 0b0100 != 0,
+            do_not_turn_left: self.rac & 0b0010 != 0,
+            do_not_turn_right: self.rac & 0b0001 != 0,
+        }
+    }
+
+    /// Glyph of the threat encounter, the same as of the BDS 3,0 reply.
+    pub fn threat_encounter(&self) -> Option<char> {
+        if self.multiple_threats {
+            Some('\u{2072}')
+        } else if self.is_active() {
+            Some('\u{2071}')
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for ThreatIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ThreatIdentity::Address(address) => write!(f, "{:06X}", address),
+            ThreatIdentity::Position {
+                altitude,
+                range,
+                bearing,
+            } => {
+                if let Some(v) = altitude {
+                    write!(f, "ALT:{}", v)?
+                }
+                if let Some(v) = range {
+                    write!(f, " RNG:{:.1}", v)?
+                }
+                if let Some(v) = bearing {
+                    write!(f, " BRG:{}", v)?
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Comma separated advisory, complements, terminated flag, multiple threats flag and threat identity.
+impl Display for ResolutionAdvisory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(v) = self.advisory() {
+            write!(f, "{}", v)?
+        }
+        write!(
+            f,
+            ",{},{},{}",
+            self.complement(),
+            self.terminated as u32,
+            self.multiple_threats as u32
+        )?;
+        if let Some(v) = self.threat {
+            write!(f, ",{}", v)
+        } else {
+            write!(f, ",")
+        }
+    }
+}
+
+fn threat_identity(message: &[u32]) -> Option<ThreatIdentity> {
+    match range_value(message, 61, 62)? {
+        1 => range_value(message, 63, 86).map(ThreatIdentity::Address),
+        2 => Some(ThreatIdentity::Position {
+            altitude: threat_altitude(message),
+            range: range_value(message, 76, 82)
+                .filter(|&v| v != 0)
+                .map(|v| (v - 1) as f64 / 10.0),
+            bearing: range_value(message, 83, 88)
+                .filter(|v| (1..=60).contains(v))
+                .map(|v| (v - 1) * 6),
+        }),
+        _ => None,
+    }
+}
+
+/// Altitude of the threat from the 13-bit TIDA field, coded as the altitude code of the reply.
+fn threat_altitude(message: &[u32]) -> Option<i32> {
+    range_value(message, 63, 75).and_then(altitude_code)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -21,4 +301,112 @@ mod tests {
             assert_eq!(threat_encounter(&message), Some('\u{2072}'));
         }
     }
+
+    #[test]
+    fn test_resolution_advisory() {
+        let squitters = [
+            (
+                "80E19718308C00052C6B4480A626",
+                ResolutionAdvisory {
+                    ara: 0b10001100000000,
+                    rac: 0,
+                    terminated: false,
+                    multiple_threats: false,
+                    threat: Some(ThreatIdentity::Address(0x4B1AD1)),
+                },
+            ),
+            (
+                "80E1971830E200052C6B44FBE187",
+                ResolutionAdvisory {
+                    ara: 0b11100010000000,
+                    rac: 0,
+                    terminated: false,
+                    multiple_threats: false,
+                    threat: Some(ThreatIdentity::Address(0x4B1AD1)),
+                },
+            ),
+            (
+                "80E197183080000B0705508ED0A4",
+                ResolutionAdvisory {
+                    ara: 0b10000000000000,
+                    rac: 0,
+                    terminated: false,
+                    multiple_threats: false,
+                    threat: Some(ThreatIdentity::Position {
+                        altitude: Some(38000),
+                        range: Some(2.0),
+                        bearing: Some(90),
+                    }),
+                },
+            ),
+        ];
+
+        for (squitter, value) in squitters.iter() {
+            if let Some(message) = decoder::message(squitter) {
+                assert_eq!(ResolutionAdvisory::from_message(&message), Some(*value));
+            }
+        }
+    }
+
+    #[test]
+    fn test_advisory() {
+        let ras = [
+            (0b11100010000000, false, "DESCEND"),
+            (0b11000110000000, false, "CROSSING CLIMB"),
+            (0b11110010000000, false, "INCREASE DESCEND"),
+            (0b11101010000000, false, "REVERSE DESCEND"),
+            (0b10100000000000, false, "LIMIT CLIMB PREVENTIVE"),
+            (0b10000000000000, false, "LIMIT DESCENT PREVENTIVE"),
+            (0b01000010000000, true, "REVERSE UP"),
+            (0b00110000000000, true, "CLIMB DOWN"),
+        ];
+
+        for (ara, multiple_threats, value) in ras.iter() {
+            let ra = ResolutionAdvisory {
+                ara: *ara,
+                rac: 0b1001,
+                terminated: false,
+                multiple_threats: *multiple_threats,
+                threat: None,
+            };
+            assert_eq!(ra.advisory().unwrap().to_string(), *value);
+            assert_eq!(ra.complement().to_string(), "NO BELOW NO RIGHT");
+        }
+    }
+
+    #[test]
+    fn test_advisory_none() {
+        let ra = ResolutionAdvisory {
+            ara: 0,
+            rac: 0,
+            terminated: true,
+            multiple_threats: false,
+            threat: None,
+        };
+        assert_eq!(ra.advisory(), None);
+        assert_eq!(ra.to_string(), ",,1,0,");
+    }
+
+    #[test]
+    fn test_resolution_advisory_bds_3_0() {
+        if let Some(message) = decoder::message("A8000096300000000000007F5EBC") {
+            assert!(resolution_advisory(&message, 21).is_some());
+            assert_eq!(resolution_advisory(&message, 4), None);
+        }
+    }
+
+    #[test]
+    fn test_resolution_advisory_broadcast() {
+        if let Some(message) = decoder::message("8D4CA13DE2E200052C6B44C2DCA2") {
+            let ra = resolution_advisory(&message, 17).unwrap();
+            assert_eq!(ra.to_string(), "DESCEND,,0,0,4B1AD1");
+        }
+    }
+
+    #[test]
+    fn test_resolution_advisory_vds() {
+        if let Some(message) = decoder::message("80E1971858B985B87005B65AFC8A") {
+            assert_eq!(ResolutionAdvisory::from_message(&message), None);
+        }
+    }
 }
@@ -0,0 +1,204 @@
+use crate::decoder::{cf, message_type, range_value};
+use std::fmt::{self, Display};
+
+/// Kind of the 24-bit address a target is reported with.
+///
+/// DF18 squitters are sent by non-transponder devices, TIS-B ground stations and
+/// ADS-R rebroadcasts, their address is not necessarily an ICAO airframe address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum AddressType {
+    #[default]
+    Icao,
+    NonIcao,
+    TisbIcao,
+    TisbNonIcao,
+    AdsrIcao,
+    AdsrNonIcao,
+}
+
+impl AddressType {
+    pub fn is_icao(&self) -> bool {
+        matches!(
+            self,
+            AddressType::Icao | AddressType::TisbIcao | AddressType::AdsrIcao
+        )
+    }
+
+    /// The non-ICAO counterpart of a TIS-B or ADS-R address type.
+    pub fn non_icao(&self) -> AddressType {
+        match self {
+            AddressType::TisbIcao => AddressType::TisbNonIcao,
+            AddressType::AdsrIcao => AddressType::AdsrNonIcao,
+            _ => *self,
+        }
+    }
+}
+
+impl Display for AddressType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let symbol = match self {
+            AddressType::Icao => ' ',
+            AddressType::NonIcao => '~',
+            AddressType::TisbIcao => 't',
+            AddressType::TisbNonIcao => 'T',
+            AddressType::AdsrIcao => 'r',
+            AddressType::AdsrNonIcao => 'R',
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+/// Retrieves the address type of a message.
+///
+/// # Arguments
+///
+/// * `message` - The message to extract the address type from.
+/// * `df` - The downlink format (DF) of the message.
+///
+/// # Returns
+///
+/// The address type, `None` for the DF18 TIS-B/ADS-R management (CF4) and reserved (CF7) messages.
+///
+/// # Examples
+///
+/// ```
+/// use squitterator::decoder::{message, address_type, AddressType};
+/// if let Some(message) = message("9540621D59C382D690C8AC4F5534") {
+///     assert_eq!(address_type(&message, 18), Some(AddressType::TisbNonIcao));
+/// }
+/// ```
+pub fn address_type(message: &[u32], df: u32) -> Option<AddressType> {
+    match df {
+        18 => match cf(message) {
+            0 => Some(AddressType::Icao),
+            1 => Some(AddressType::NonIcao),
+            2 => match imf(message) {
+                Some(1) => Some(AddressType::TisbNonIcao),
+                _ => Some(AddressType::TisbIcao),
+            },
+            3 => match range_value(message, 33, 33) {
+                Some(1) => Some(AddressType::TisbNonIcao),
+                _ => Some(AddressType::TisbIcao),
+            },
+            5 => Some(AddressType::TisbNonIcao),
+            6 => match imf(message) {
+                Some(1) => Some(AddressType::AdsrNonIcao),
+                _ => Some(AddressType::AdsrIcao),
+            },
+            _ => None,
+        },
+        _ => Some(AddressType::Icao),
+    }
+}
+
+/// Tells whether the address type of the message is known.
+///
+/// The fine TIS-B (CF2) and ADS-R (CF6) messages without the IMF (TC 1-4, 23-31) don't
+/// tell an ICAO address from a non-ICAO one, `address_type` reports them as ICAO and the
+/// address type recorded for the target should be used instead.
+///
+/// # Examples
+///
+/// ```
+/// use squitterator::decoder::{message, address_type_known};
+/// if let Some(message) = message("9240621DF8000000000000000000") {
+///     assert!(!address_type_known(&message, 18));
+/// }
+/// ```
+pub fn address_type_known(message: &[u32], df: u32) -> bool {
+    !(df == 18 && matches!(cf(message), 2 | 6) && imf(message).is_none())
+}
+
+/// Retrieves the ICAO/Mode A Flag (IMF) of the fine TIS-B and ADS-R messages.
+///
+/// The flag takes the place of the single antenna flag of the airborne position,
+/// the time bit of the surface position and the intent change flag of the velocity.
+pub(crate) fn imf(message: &[u32]) -> Option<u32> {
+    let bit = match message_type(message).0 {
+        5..=8 => 53,
+        9..=18 | 20..=22 => 40,
+        19 => 41,
+        _ => return None,
+    };
+    range_value(message, bit, bit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::message;
+
+    #[test]
+    fn test_address_type() {
+        let squitters = [
+            ("8D40621D58C382D690C8AC2863A7", 17, Some(AddressType::Icao)),
+            ("9040621D58C382D690C8AC556F52", 18, Some(AddressType::Icao)),
+            (
+                "9140621D58C382D690C8AC0D1E2A",
+                18,
+                Some(AddressType::NonIcao),
+            ),
+            (
+                "9240621D58C382D690C8ACE58DA2",
+                18,
+                Some(AddressType::TisbIcao),
+            ),
+            (
+                "9240621D59C382D690C8AC39F755",
+                18,
+                Some(AddressType::TisbNonIcao),
+            ),
+            (
+                "9340621D03871438B5A645743DAA",
+                18,
+                Some(AddressType::TisbIcao),
+            ),
+            ("9440621D58C382D690C8ACCB5EBB", 18, None),
+            (
+                "9640621D59C382D690C8ACA7C6BC",
+                18,
+                Some(AddressType::AdsrNonIcao),
+            ),
+        ];
+
+        for (squitter, df, value) in squitters.iter() {
+            if let Some(message) = message(squitter) {
+                assert_eq!(
+                    address_type(&message, *df),
+                    *value,
+                    "Squitter: {}",
+                    squitter
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_address_type_known() {
+        let squitters = [
+            ("9240621D58C382D690C8ACE58DA2", true),
+            ("9240621DF8000000000000000000", false),
+            ("9640621D20000000000000000000", false),
+            ("9340621D03871438B5A645743DAA", true),
+            ("9040621DF8000000000000000000", true),
+        ];
+        for (squitter, value) in squitters.iter() {
+            if let Some(message) = message(squitter) {
+                assert_eq!(address_type_known(&message, 18), *value, "{}", squitter);
+            }
+        }
+        assert_eq!(AddressType::TisbIcao.non_icao(), AddressType::TisbNonIcao);
+        assert_eq!(AddressType::AdsrIcao.non_icao(), AddressType::AdsrNonIcao);
+        assert_eq!(AddressType::Icao.non_icao(), AddressType::Icao);
+    }
+
+    #[test]
+    fn test_imf() {
+        if let Some(message) = message("9640621D59C382D690C8ACA7C6BC") {
+            assert_eq!(imf(&message), Some(1));
+        }
+        if let Some(message) = message("9240621D58C382D690C8ACE58DA2") {
+            assert_eq!(imf(&message), Some(0));
+        }
+    }
+}
@@ -1,3 +1,20 @@
-pub(crate) fn altitude_gnss(message: &[u32]) -> Option<u32> {
-    crate::decoder::range_value(message, 49, 60)
+/// Retrieves the GNSS height (HAE) of the TC 20-22 airborne position in feet.
+///
+/// The 12-bit altitude field holds the height in metres.
+pub(crate) fn altitude_gnss(message: &[u32]) -> Option<i32> {
+    crate::decoder::range_value(message, 41, 52).map(|v| (v as f64 * 3.28084).round() as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder;
+
+    #[test]
+    fn test_altitude_gnss() {
+        // TC 20, 3048 metres
+        if let Some(message) = decoder::message("8D4840D6A0BE800000000068AFD4") {
+            assert_eq!(altitude_gnss(&message), Some(10000));
+        }
+    }
 }
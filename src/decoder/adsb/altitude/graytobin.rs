@@ -1,74 +1,70 @@
-use crate::decoder;
+/// Decodes the Gillham (Gray) coded altitude into feet, 100 feet increments from -1200.
+///
+/// The code holds the C1 A1 C2 A2 C4 A4 B1 D1 B2 D2 B4 D4 bits of the altitude code
+/// (the M bit removed) in bits 13 to 2, as returned by `ma_code` and `me_code`.
+/// Illegal C1 C2 C4 combinations return `None`.
+pub(super) fn graytobin(code: u16) -> Option<i32> {
+    let bit = |n: u16| ((code >> n) & 1) as u32;
+    // D1 D2 D4 A1 A2 A4 B1 B2 B4 select the 500 feet band
+    let gray_500 = [6, 4, 2, 12, 10, 8, 7, 5, 3]
+        .iter()
+        .fold(0, |acc, &n| (acc << 1) | bit(n));
+    // C1 C2 C4 select 100 feet within the band
+    let gray_100 = (bit(13) << 2) | (bit(11) << 1) | bit(9);
 
-pub(super) fn graytobin(message: &[u32]) -> (u32, u32) {
-    if let Some(code) = decoder::ma_code(message) {
-        let n = ((code >> 4) & 1) << 10
-            | ((code >> 2) & 1) << 9
-            | ((code >> 12) & 1) << 8
-            | ((code >> 10) & 1) << 7
-            | ((code >> 8) & 1) << 6
-            | ((code >> 7) & 1) << 5
-            | ((code >> 5) & 1) << 4
-            | ((code >> 3) & 1) << 3
-            | ((code >> 13) & 1) << 2
-            | ((code >> 11) & 1) << 1
-            | ((code >> 13) & 1);
-        let mut mask = 0x80;
-        let mut cp = false;
-        let mut result = 0;
-        for _ in 1..=16 {
-            if (n & mask) != 0 {
-                cp = !cp;
-            }
-            if cp {
-                result |= mask;
-            }
-            mask >>= 1;
-        }
+    let n_500 = gray_to_binary(gray_500) as i32;
+    let n_100 = match gray_to_binary(gray_100) {
+        0 | 5 | 6 => return None,
+        7 => 5,
+        n => n as i32,
+    };
+    // the 100 feet code runs backwards in the odd 500 feet bands
+    let n_100 = match n_500 % 2 {
+        1 => 6 - n_100,
+        _ => n_100,
+    };
+    Some(n_500 * 500 + n_100 * 100 - 1300)
+}
 
-        let sub = n & 7;
-        let high = result >> 3;
-        let low = match high % 2 {
-            0 => match sub {
-                4 => 4,
-                6 => 3,
-                3 => 1,
-                2 => 2,
-                _ => 0,
-            },
-            _ => match sub {
-                1 => 4,
-                3 => 3,
-                6 => 1,
-                2 => 2,
-                _ => 0,
-            },
-        };
-        (high as u32, low as u32)
-    } else {
-        (0, 0)
+fn gray_to_binary(gray: u32) -> u32 {
+    let mut result = gray;
+    let mut shift = gray >> 1;
+    while shift != 0 {
+        result ^= shift;
+        shift >>= 1;
     }
+    result
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::decoder;
 
     #[test]
     fn test_graytobin() {
         if let Some(message) = decoder::message("A8281200200464B3CF7820CD194C") {
-            let (high, low) = graytobin(&message);
-            assert_eq!(high, 31);
-            assert_eq!(low, 0);
+            let code = decoder::ma_code(&message).unwrap();
+            assert_eq!(graytobin(code), Some(14300));
         }
     }
 
     #[test]
     fn test_graytobin_e() {
         if let Some(message) = decoder::message("A020100A10020A80F000004F24AF") {
-            let (high, low) = graytobin(&message);
-            assert_eq!(high, 2);
-            assert_eq!(low, 0);
+            let code = decoder::ma_code(&message).unwrap();
+            assert_eq!(graytobin(code), Some(200));
         }
     }
+
+    #[test]
+    fn test_graytobin_below_sea_level() {
+        // C1 only, the lowest band
+        assert_eq!(graytobin(1 << 13), Some(-800));
+        assert_eq!(graytobin((1 << 13) | (1 << 11)), Some(-900));
+        assert_eq!(graytobin(1 << 11), Some(-1000));
+        // C4 only, the lowest code
+        assert_eq!(graytobin(1 << 9), Some(-1200));
+        assert_eq!(graytobin(0), None);
+    }
 }
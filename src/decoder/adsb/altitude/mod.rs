@@ -8,15 +8,15 @@ pub(crate) use gnss::*;
 use graytobin::graytobin;
 use log::info;
 
-use crate::decoder::{ma_code, me_code};
+use crate::decoder::{flag_and_range_value, ma_code, me_code};
 
-pub fn altitude(message: &[u32], df: u32) -> Option<u32> {
+pub fn altitude(message: &[u32], df: u32) -> Option<i32> {
     let code = match df {
-        17 => me_code(message),
+        17 | 18 => me_code(message),
         _ => ma_code(message),
     };
 
-    altitude_value(message, code).and_then(|a| {
+    altitude_value(code).and_then(|a| {
         if a < 100000 {
             Some(a)
         } else {
@@ -32,25 +32,35 @@ pub fn altitude(message: &[u32], df: u32) -> Option<u32> {
     })
 }
 
-fn altitude_value(message: &[u32], code: Option<u16>) -> Option<u32> {
-    match code {
-        Some(code) => match code & 0b10 {
-            0 => match code & 1 {
-                0 => {
-                    let (high, low) = graytobin(message);
-                    let value = high * 500 + low * 100;
-                    match value {
-                        1200.. => Some(high * 500 + low * 100 - 1200),
-                        _ => None,
-                    }
-                }
-                _ => Some((((code >> 7) << 4) | ((code >> 2) & 0b1111)) as u32 * 25 - 1000),
-            },
-            _ => Some(
-                ((((code >> 7) << 4) & 0b11111110000 | (code >> 2) & 0b1111) as f32 * 0.31) as u32,
-            ),
-        },
-        None => None,
+/// Retrieves the pressure altitude of the coarse TIS-B airborne position.
+///
+/// The 12-bit altitude code starts one bit earlier than in the airborne position squitter.
+pub(crate) fn altitude_coarse(message: &[u32]) -> Option<i32> {
+    let code =
+        flag_and_range_value(message, 47, 40, 51).map(|(flag, value)| ((value << 2) | flag) as u16);
+    altitude_value(code).filter(|&a| a < 100000)
+}
+
+/// Decodes the 13-bit altitude code (AC) in the order of the reply, C1 in bit 12, M in bit 6
+/// and Q in bit 4.
+pub(crate) fn altitude_code(code: u32) -> Option<i32> {
+    let without_m = ((code >> 7) << 6) | (code & 0b111111);
+    let m = (code >> 6) & 1;
+    let q = (code >> 4) & 1;
+    altitude_value(Some(((without_m << 2) | (m << 1) | q) as u16))
+}
+
+/// Decodes the altitude code in the `ma_code` layout, bits 13 to 2 hold the code without
+/// the M bit, bit 1 is the M bit and bit 0 the Q bit.
+///
+/// M = 1 is the metric altitude in metres, Q = 1 the 25 feet increments from -1000 feet,
+/// otherwise the Gillham coded 100 feet increments from -1200 feet.
+fn altitude_value(code: Option<u16>) -> Option<i32> {
+    let code = code?;
+    match ((code >> 1) & 1, code & 1) {
+        (0, 0) => graytobin(code),
+        (0, _) => Some((((code >> 7) << 4) | ((code >> 2) & 0b1111)) as i32 * 25 - 1000),
+        _ => Some(((code >> 2) as f64 * 3.28084).round() as i32),
     }
 }
 
@@ -68,12 +78,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_alt_coarse() {
+        if let Some(message) = decoder::message("9340621D03871438B5A645743DAA") {
+            assert_eq!(altitude_coarse(&message), Some(38000));
+        }
+    }
+
     #[test]
     fn test_alt_e() {
         if let Some(message) = decoder::message("A020100A10020A80F000004F24AF") {
             let df = decoder::df(&message).unwrap();
             let result = altitude(&message, df);
-            assert_eq!(result, None);
+            assert_eq!(result, Some(200));
         }
     }
+
+    #[test]
+    fn test_alt_below_sea_level() {
+        let squitters = [
+            // Gillham C1, rec/df4-alt-error.txt
+            ("209E3000820002", -800),
+            // Q bit, N = 1
+            ("20000011", -975),
+        ];
+        for (squitter, value) in squitters.iter() {
+            let message = squitter
+                .chars()
+                .map(|c| c.to_digit(16).unwrap())
+                .collect::<Vec<u32>>();
+            assert_eq!(altitude(&message, 4), Some(*value), "{}", squitter);
+        }
+    }
+
+    #[test]
+    fn test_altitude_code() {
+        // Q bit, N = 1520
+        assert_eq!(altitude_code(0b1011110110000), Some(37000));
+        // Q bit, N = 1
+        assert_eq!(altitude_code(0b0000000010001), Some(-975));
+        // M bit, 1000 metres
+        assert_eq!(altitude_code(0b0011111101000), Some(3281));
+    }
 }
@@ -0,0 +1,104 @@
+use crate::decoder::range_value;
+use std::fmt::{self, Display};
+
+/// Emergency/priority status of the aircraft.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Emergency {
+    General,
+    Medical,
+    MinimumFuel,
+    NoCommunications,
+    UnlawfulInterference,
+    Downed,
+}
+
+impl Emergency {
+    /// Emergency implied by the special purpose Mode A codes 7500, 7600 and 7700.
+    pub fn from_squawk(squawk: u32) -> Option<Self> {
+        match squawk {
+            7500 => Some(Emergency::UnlawfulInterference),
+            7600 => Some(Emergency::NoCommunications),
+            7700 => Some(Emergency::General),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Emergency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let symbol = match self {
+            Emergency::General => 'G',
+            Emergency::Medical => 'M',
+            Emergency::MinimumFuel => 'F',
+            Emergency::NoCommunications => 'C',
+            Emergency::UnlawfulInterference => 'U',
+            Emergency::Downed => 'D',
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+/// Retrieves the emergency state of the TC 28 subtype 1 message.
+pub(crate) fn emergency(message: &[u32]) -> Option<Emergency> {
+    match range_value(message, 41, 43)? {
+        1 => Some(Emergency::General),
+        2 => Some(Emergency::Medical),
+        3 => Some(Emergency::MinimumFuel),
+        4 => Some(Emergency::NoCommunications),
+        5 => Some(Emergency::UnlawfulInterference),
+        6 => Some(Emergency::Downed),
+        _ => None,
+    }
+}
+
+/// Retrieves the Mode A code of the TC 28 subtype 1 message.
+///
+/// The 13-bit code follows the C1 A1 C2 A2 C4 A4 X B1 D1 B2 D2 B4 D4 order
+/// of the identity reply.
+pub(crate) fn emergency_squawk(message: &[u32]) -> Option<u32> {
+    range_value(message, 44, 56).map(|code| {
+        let digit = |b1: u32, b2: u32, b4: u32| {
+            ((code >> b4) & 1) << 2 | ((code >> b2) & 1) << 1 | (code >> b1) & 1
+        };
+        digit(11, 9, 7) * 1000 + digit(5, 3, 1) * 100 + digit(12, 10, 8) * 10 + digit(4, 2, 0)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::message;
+
+    #[test]
+    fn test_emergency() {
+        let squitters = [
+            (
+                "8D4CA13DE12AAA00000000979A10",
+                Some(Emergency::General),
+                7700,
+            ),
+            (
+                "8D4CA13DE18A8A00000000C37276",
+                Some(Emergency::NoCommunications),
+                7600,
+            ),
+            ("8D4CA13DE11C0900000000BE304D", None, 1234),
+        ];
+
+        for (squitter, state, squawk) in squitters.iter() {
+            if let Some(message) = message(squitter) {
+                assert_eq!(emergency(&message), *state, "Squitter: {}", squitter);
+                assert_eq!(emergency_squawk(&message), Some(*squawk));
+            }
+        }
+    }
+
+    #[test]
+    fn test_emergency_from_squawk() {
+        assert_eq!(
+            Emergency::from_squawk(7500),
+            Some(Emergency::UnlawfulInterference)
+        );
+        assert_eq!(Emergency::from_squawk(7000), None);
+    }
+}
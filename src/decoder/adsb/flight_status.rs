@@ -0,0 +1,75 @@
+use crate::decoder::range_value;
+
+/// Flight status (FS) of the DF4, DF5, DF20 and DF21 replies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FlightStatus {
+    /// The Mode A code has changed within the last 18 seconds.
+    pub alert: bool,
+    /// Special position identification, the pilot has pressed IDENT.
+    pub spi: bool,
+    /// `None` when the FS code does not tell airborne from on-ground.
+    pub on_ground: Option<bool>,
+}
+
+/// Retrieves the flight status of the reply.
+pub(crate) fn flight_status(message: &[u32]) -> Option<FlightStatus> {
+    let (alert, spi, on_ground) = match range_value(message, 6, 8)? {
+        0 => (false, false, Some(false)),
+        1 => (false, false, Some(true)),
+        2 => (true, false, Some(false)),
+        3 => (true, false, Some(true)),
+        4 => (true, true, None),
+        5 => (false, true, None),
+        _ => return None,
+    };
+    Some(FlightStatus {
+        alert,
+        spi,
+        on_ground,
+    })
+}
+
+/// Retrieves the downlink request (DR) of the reply.
+pub(crate) fn downlink_request(message: &[u32]) -> Option<u32> {
+    range_value(message, 9, 13)
+}
+
+/// Retrieves the interrogator identifier (IIS) and the reservation type (IDS)
+/// of the utility message (UM) of the reply.
+pub(crate) fn utility_message(message: &[u32]) -> Option<(u32, u32)> {
+    Some((range_value(message, 14, 17)?, range_value(message, 18, 19)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::message;
+
+    #[test]
+    fn test_flight_status() {
+        if let Some(message) = message("A8281200200464B3CF7820CD194C") {
+            assert_eq!(
+                flight_status(&message),
+                Some(FlightStatus {
+                    alert: false,
+                    spi: false,
+                    on_ground: Some(false),
+                })
+            );
+            assert_eq!(downlink_request(&message), Some(5));
+            assert_eq!(utility_message(&message), Some((0, 0)));
+        }
+        if let Some(message) = message("2D23A000000000") {
+            assert_eq!(
+                flight_status(&message),
+                Some(FlightStatus {
+                    alert: false,
+                    spi: true,
+                    on_ground: None,
+                })
+            );
+            assert_eq!(downlink_request(&message), Some(4));
+            assert_eq!(utility_message(&message), Some((7, 1)));
+        }
+    }
+}
@@ -1,18 +1,43 @@
+/// Retrieves the ground speed in knots of the surface position message.
+///
+/// Code 0 means no information available and codes 125-127 are reserved.
+/// Code 124 stands for 175 kt or more.
 pub(crate) fn ground_movement(message: &[u32]) -> Option<f64> {
     if let Some(value) = crate::decoder::range_value(message, 38, 44) {
-        let value = value as f64;
-        match value {
-            1.0 => Some(0.0),
-            2.0..=8.0 => Some(value / 8.0),
-            9.0..=12.0 => Some(value / 4.0),
-            13.0..=38.0 => Some(value / 2.0),
-            39.0..=93.0 => Some(value),
-            94.0..=108.0 => Some(value * 2.0),
-            109.0..=123.0 => Some(value * 5.0),
-            124.0 => Some(175.0),
-            _ => None,
-        }
+        let (base, step, kts) = match value {
+            1 => return Some(0.0),
+            2..=8 => (2, 0.125, 0.125),
+            9..=12 => (9, 0.25, 1.0),
+            13..=38 => (13, 0.5, 2.0),
+            39..=93 => (39, 1.0, 15.0),
+            94..=108 => (94, 2.0, 70.0),
+            109..=123 => (109, 5.0, 100.0),
+            124 => return Some(175.0),
+            _ => return None,
+        };
+        Some(kts + (value - base) as f64 * step)
     } else {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::{ground_track, message};
+
+    #[test]
+    fn test_ground_movement() {
+        let squitters = [
+            ("8C4841753AAB238733C8CD4020B1", Some(18.0), Some(140)),
+            ("8C4841753A8A35323FAEBDAC702D", Some(16.0), Some(98)),
+        ];
+
+        for (squitter, movement, track) in squitters.iter() {
+            if let Some(message) = message(squitter) {
+                assert_eq!(ground_movement(&message), *movement, "{}", squitter);
+                assert_eq!(ground_track(&message), *track, "{}", squitter);
+            }
+        }
+    }
+}
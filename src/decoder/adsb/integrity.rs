@@ -0,0 +1,123 @@
+use crate::decoder::range_value;
+
+/// Navigation Uncertainty Category of the position (NUCp) of the version 0 messages.
+pub(crate) fn nuc_p(message_type: u32) -> Option<u32> {
+    match message_type {
+        5..=8 => Some(14 - message_type),
+        9..=18 => Some(18 - message_type),
+        20 => Some(9),
+        21 => Some(8),
+        22 => Some(0),
+        _ => None,
+    }
+}
+
+/// NIC with the containment radius closest to the one of the given NUCp.
+pub(crate) fn nic_from_nuc(nuc_p: u32) -> u32 {
+    match nuc_p {
+        9 => 11,
+        8 => 10,
+        7 => 8,
+        6 => 7,
+        5 => 6,
+        4 => 5,
+        3 => 4,
+        1 | 2 => 1,
+        _ => 0,
+    }
+}
+
+/// Navigation Integrity Category (NIC) of the version 1 and 2 position messages.
+///
+/// # Arguments
+///
+/// * `message_type` - The type code of the position message.
+/// * `version` - The ADS-B version, 1 uses the NIC supplement A only.
+/// * `supplements` - The NIC supplements A, B (airborne) and C (surface).
+pub(crate) fn nic(message_type: u32, version: u32, supplements: (u32, u32, u32)) -> Option<u32> {
+    let (a, b, c) = match version {
+        1 => (supplements.0, supplements.0, supplements.0),
+        _ => supplements,
+    };
+    match message_type {
+        5 | 9 | 20 => Some(11),
+        6 | 10 | 21 => Some(10),
+        7 => Some(if a == 1 { 9 } else { 8 }),
+        8 => match (a, c) {
+            (1, 1) if version > 1 => Some(7),
+            (1, _) | (0, 1) if version > 1 => Some(6),
+            _ => Some(0),
+        },
+        11 => Some(if a == 1 && b == 1 { 9 } else { 8 }),
+        12 => Some(7),
+        13 => Some(6),
+        14 => Some(5),
+        15 => Some(4),
+        16 => Some(if a == 1 && b == 1 { 3 } else { 2 }),
+        17 => Some(1),
+        18 | 22 => Some(0),
+        _ => None,
+    }
+}
+
+/// Retrieves the NIC supplement B of the version 2 airborne position.
+pub(crate) fn nic_supplement_b(message: &[u32]) -> Option<u32> {
+    range_value(message, 40, 40)
+}
+
+/// Retrieves the Navigation Accuracy Category of the velocity (NACv, NUCr in version 0).
+pub(crate) fn nacv(message: &[u32]) -> Option<u32> {
+    range_value(message, 43, 45)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::message;
+
+    #[test]
+    fn test_nuc_p() {
+        assert_eq!(nuc_p(9), Some(9));
+        assert_eq!(nuc_p(18), Some(0));
+        assert_eq!(nuc_p(6), Some(8));
+        assert_eq!(nuc_p(19), None);
+        assert_eq!(nic_from_nuc(7), 8);
+    }
+
+    #[test]
+    fn test_nic() {
+        let cases = [
+            (11, 2, (1, 1, 0), Some(9)),
+            (11, 2, (1, 0, 0), Some(8)),
+            (11, 1, (1, 0, 0), Some(9)),
+            (16, 2, (1, 1, 0), Some(3)),
+            (16, 2, (0, 0, 0), Some(2)),
+            (8, 2, (1, 1, 1), Some(7)),
+            (8, 2, (0, 0, 1), Some(6)),
+            (8, 1, (1, 0, 0), Some(0)),
+            (7, 1, (1, 0, 0), Some(9)),
+            (19, 2, (0, 0, 0), None),
+        ];
+
+        for (message_type, version, supplements, value) in cases.iter() {
+            assert_eq!(
+                nic(*message_type, *version, *supplements),
+                *value,
+                "TC:{} V:{} S:{:?}",
+                message_type,
+                version,
+                supplements
+            );
+        }
+    }
+
+    #[test]
+    fn test_nacv() {
+        if let Some(message) = message("8D485020994409940838175B284F") {
+            assert_eq!(nacv(&message), Some(0));
+        }
+        if let Some(message) = message("8DA05F219B06B6AF189400CBC33F") {
+            assert_eq!(nacv(&message), Some(0));
+        }
+    }
+}
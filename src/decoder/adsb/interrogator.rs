@@ -0,0 +1,81 @@
+use crate::decoder::{ic, utility_message};
+use std::fmt::{self, Display};
+
+/// Identifier of the Mode S interrogator which elicited the reply, or which holds a
+/// multisite reservation of the transponder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Interrogator {
+    /// Interrogator identifier (II) 0-15, II 0 on DF11 is a spontaneous acquisition squitter.
+    II(u32),
+    /// Surveillance identifier (SI) 1-63.
+    SI(u32),
+    /// Interrogator identifier 1-15 of the IIS subfield, the interrogator holding the
+    /// multisite reservation, not necessarily the one which elicited the reply.
+    Reservation(u32),
+}
+
+impl Interrogator {
+    /// DF11 with II 0 is not a reply to an interrogation.
+    pub fn is_acquisition_squitter(&self) -> bool {
+        *self == Interrogator::II(0)
+    }
+}
+
+impl Display for Interrogator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Interrogator::II(v) => write!(f, "II{:02}", v),
+            Interrogator::SI(v) => write!(f, "SI{:02}", v),
+            Interrogator::Reservation(v) => write!(f, "RS{:02}", v),
+        }
+    }
+}
+
+/// Retrieves the interrogator of the reply.
+///
+/// The all-call reply (DF11) overlays the parity with the code label (CL) and the
+/// interrogator code (IC). The surveillance replies (DF4, DF5, DF20 and DF21) overlay the
+/// parity with the address, the IIS subfield of the utility message names the interrogator
+/// holding a multisite reservation instead, IIS 0 means there is no reservation.
+pub(crate) fn interrogator(message: &[u32], df: u32) -> Option<Interrogator> {
+    match df {
+        11 => ic(message).map(|code| match code >> 4 {
+            0 => Interrogator::II(code & 0xF),
+            cl => Interrogator::SI((code & 0xF) + 16 * (cl - 1)),
+        }),
+        4 | 5 | 20 | 21 => utility_message(message)
+            .map(|(iis, _)| iis)
+            .filter(|&iis| iis != 0)
+            .map(Interrogator::Reservation),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::message;
+
+    #[test]
+    fn test_interrogator() {
+        let squitters = [
+            ("5D4CA2D468FE2F", 11, Some(Interrogator::II(0))),
+            ("5D4CA2D468FE2C", 11, Some(Interrogator::II(3))),
+            ("5D4CA2D468FE3E", 11, Some(Interrogator::SI(1))),
+            ("5D4CA2D468FE60", 11, Some(Interrogator::SI(63))),
+            ("5D4CA2D468FE7F", 11, None),
+            ("28001B8A3A61AB", 5, None),
+            ("28069B8A3A61AB", 5, Some(Interrogator::Reservation(13))),
+            ("5D4CA2D468FE3F", 11, None),
+        ];
+
+        for (squitter, df, value) in squitters.iter() {
+            if let Some(message) = message(squitter) {
+                assert_eq!(interrogator(&message, *df), *value, "{}", squitter);
+            }
+        }
+        assert_eq!(Interrogator::SI(63).to_string(), "SI63");
+        assert_eq!(Interrogator::Reservation(3).to_string(), "RS03");
+        assert!(Interrogator::II(0).is_acquisition_squitter());
+    }
+}
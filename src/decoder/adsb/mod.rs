@@ -1,22 +1,44 @@
 mod acas;
+mod address_type;
 mod ais;
 mod altitude;
+mod emergency;
+mod flight_status;
 mod ground_movement;
 mod icao;
+mod integrity;
+mod interrogator;
+mod operational_status;
 mod position;
 mod squawk;
 mod surveillance_status;
+mod target_state;
+mod tisb;
 mod version;
 mod vertical_rate;
 
+pub use address_type::*;
+pub use emergency::Emergency;
+pub use flight_status::FlightStatus;
 pub use icao::*;
+pub use interrogator::Interrogator;
+pub use operational_status::OperationalStatus;
+pub use target_state::{AutopilotModes, TargetState};
 
 pub(crate) use acas::*;
+pub use acas::{resolution_advisory, Ara, Rac, ResolutionAdvisory, ThreatIdentity};
 pub(crate) use ais::*;
 pub(crate) use altitude::*;
+pub(crate) use emergency::*;
+pub(crate) use flight_status::*;
 pub(crate) use ground_movement::*;
+pub(crate) use integrity::*;
+pub(crate) use interrogator::*;
+pub(crate) use operational_status::*;
 pub(crate) use position::*;
 pub(crate) use squawk::*;
 pub(crate) use surveillance_status::*;
+pub(crate) use target_state::*;
+pub(crate) use tisb::*;
 pub(crate) use version::*;
 pub(crate) use vertical_rate::*;
@@ -0,0 +1,203 @@
+use crate::decoder::range_value;
+
+/// ADS-B aircraft operational status message (TC 31).
+///
+/// The capability class and operational mode codes are kept as received, the fields
+/// are decoded according to the ADS-B version and the airborne/surface subtype of the
+/// message. Fields which do not exist in the given version or subtype are `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct OperationalStatus {
+    pub version: u32,
+    pub surface: bool,
+    pub capability_class: u32,
+    pub operational_mode: u32,
+    pub acas_operational: Option<bool>,
+    pub cdti: Option<bool>,
+    pub es_in: Option<bool>,
+    pub uat_in: Option<bool>,
+    pub air_referenced_velocity: Option<bool>,
+    pub target_state_report: Option<bool>,
+    pub trajectory_change_report: Option<u32>,
+    pub position_offset_applied: Option<bool>,
+    pub low_power: Option<bool>,
+    pub nacv: Option<u32>,
+    pub nic_supplement_c: Option<u32>,
+    pub length_width: Option<(f64, f64)>,
+    pub ra_active: Option<bool>,
+    pub ident: Option<bool>,
+    pub single_antenna: Option<bool>,
+    pub system_design_assurance: Option<u32>,
+    pub gps_antenna_offset: Option<(i32, u32)>,
+    pub nic_supplement_a: Option<u32>,
+    pub nacp: Option<u32>,
+    pub gva: Option<u32>,
+    pub sil: Option<u32>,
+    pub sil_supplement: Option<u32>,
+    pub nic_baro: Option<u32>,
+    pub track_angle_heading: Option<u32>,
+    pub horizontal_reference_direction: Option<u32>,
+}
+
+fn flag(message: &[u32], bit: u32) -> Option<bool> {
+    range_value(message, bit, bit).map(|v| v == 1)
+}
+
+/// Decodes the operational status message, subtype 0 (airborne) or 1 (surface).
+pub(crate) fn operational_status(message: &[u32]) -> Option<OperationalStatus> {
+    let surface = match range_value(message, 38, 40)? {
+        0 => false,
+        1 => true,
+        _ => return None,
+    };
+    let version = range_value(message, 73, 75)?;
+    let mut status = OperationalStatus {
+        version,
+        surface,
+        capability_class: range_value(message, 41, 56)?,
+        operational_mode: range_value(message, 57, 72)?,
+        ..Default::default()
+    };
+    if version == 0 {
+        return Some(status);
+    }
+
+    status.nic_supplement_a = range_value(message, 76, 76);
+    status.nacp = range_value(message, 77, 80);
+    status.sil = range_value(message, 83, 84);
+    status.horizontal_reference_direction = range_value(message, 86, 86);
+    status.ra_active = flag(message, 59);
+    status.ident = flag(message, 60);
+    match surface {
+        false => {
+            status.air_referenced_velocity = flag(message, 47);
+            status.target_state_report = flag(message, 48);
+            status.trajectory_change_report = range_value(message, 49, 50);
+            status.nic_baro = range_value(message, 85, 85);
+        }
+        true => {
+            status.capability_class >>= 4;
+            status.position_offset_applied = flag(message, 43);
+            status.length_width = range_value(message, 53, 56).and_then(length_width);
+            status.track_angle_heading = range_value(message, 85, 85);
+        }
+    }
+    match (version, surface) {
+        (1, false) => {
+            status.acas_operational = flag(message, 43).map(|not_acas| !not_acas);
+            status.cdti = flag(message, 44);
+        }
+        (1, true) => {
+            status.cdti = flag(message, 44);
+            status.low_power = flag(message, 45);
+        }
+        (_, false) => {
+            status.acas_operational = flag(message, 43);
+            status.es_in = flag(message, 44);
+            status.uat_in = flag(message, 51);
+            status.gva = range_value(message, 81, 82);
+        }
+        (_, true) => {
+            status.es_in = flag(message, 44);
+            status.low_power = flag(message, 47);
+            status.uat_in = flag(message, 48);
+            status.nacv = range_value(message, 49, 51);
+            status.nic_supplement_c = range_value(message, 52, 52);
+            status.gps_antenna_offset = gps_antenna_offset(message);
+        }
+    }
+    if version >= 2 {
+        status.single_antenna = flag(message, 62);
+        status.system_design_assurance = range_value(message, 63, 64);
+        status.sil_supplement = range_value(message, 87, 87);
+    }
+    Some(status)
+}
+
+/// Upper bounds of the length and width of the aircraft in metres.
+fn length_width(code: u32) -> Option<(f64, f64)> {
+    let widths = [
+        (23.0, 23.0),
+        (28.5, 34.0),
+        (33.0, 38.0),
+        (39.5, 45.0),
+        (45.0, 52.0),
+        (59.5, 67.0),
+        (72.5, 80.0),
+        (80.0, 90.0),
+    ];
+    let lengths = [15.0, 25.0, 35.0, 45.0, 55.0, 65.0, 75.0, 85.0];
+    match code {
+        0 => None,
+        _ => {
+            let category = (code >> 1) as usize;
+            let width = match code & 1 {
+                0 => widths[category].0,
+                _ => widths[category].1,
+            };
+            Some((lengths[category], width))
+        }
+    }
+}
+
+/// Lateral (negative to the left) and longitudinal (aft of the nose) GPS antenna offset in metres.
+fn gps_antenna_offset(message: &[u32]) -> Option<(i32, u32)> {
+    let lateral = match (range_value(message, 65, 65)?, range_value(message, 66, 67)?) {
+        (0, 0) => return None,
+        (0, v) => -(v as i32 * 2),
+        (_, v) => v as i32 * 2,
+    };
+    let longitudinal = match range_value(message, 68, 72)? {
+        0 => return None,
+        1 => 0,
+        v => (v - 1) * 2,
+    };
+    Some((lateral, longitudinal))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::message;
+
+    #[test]
+    fn test_operational_status_airborne_v2() {
+        if let Some(message) = message("8D4CA13DF8200002004AB897839D") {
+            let result = operational_status(&message).unwrap();
+            assert_eq!(result.version, 2);
+            assert!(!result.surface);
+            assert_eq!(result.acas_operational, Some(true));
+            assert_eq!(result.nic_supplement_a, Some(0));
+            assert_eq!(result.nacp, Some(10));
+            assert_eq!(result.gva, Some(2));
+            assert_eq!(result.sil, Some(3));
+            assert_eq!(result.nic_baro, Some(1));
+            assert_eq!(result.sil_supplement, Some(0));
+            assert_eq!(result.system_design_assurance, Some(2));
+            assert_eq!(result.length_width, None);
+        }
+    }
+
+    #[test]
+    fn test_operational_status_surface_v2() {
+        if let Some(message) = message("8D4CA13DF9000B00A6493833AD75") {
+            let result = operational_status(&message).unwrap();
+            assert_eq!(result.version, 2);
+            assert!(result.surface);
+            assert_eq!(result.length_width, Some((65.0, 67.0)));
+            assert_eq!(result.gps_antenna_offset, Some((2, 10)));
+            assert_eq!(result.nacv, Some(0));
+            assert_eq!(result.track_angle_heading, Some(1));
+            assert_eq!(result.acas_operational, None);
+            assert_eq!(result.nic_baro, None);
+        }
+    }
+
+    #[test]
+    fn test_operational_status_v0() {
+        if let Some(message) = message("8D4CA13DF800000000000072A734") {
+            let result = operational_status(&message).unwrap();
+            assert_eq!(result.version, 0);
+            assert_eq!(result.nacp, None);
+        }
+    }
+}
@@ -10,7 +10,6 @@ pub(crate) fn cpr_location(
     cpr_lat: &[u32; 2],
     cpr_lon: &[u32; 2],
     cpr_form: u32,
-    coeff: i32,
 ) -> Option<(f64, f64)> {
     let div = (1 << 17) as f64;
     let adl0 = 6.0; // 360 / 60
@@ -25,31 +24,112 @@ pub(crate) fn cpr_location(
     let nl = [nl(rlat[0]), nl(rlat[1])];
     match nl[0] == nl[1] {
         true => {
-            let (ni, nlt, lngt) = match cpr_form {
-                1 => (
-                    *[nl[1] / coeff - 1, 1].iter().max().unwrap(), // coeff = 4 for ground location
-                    nl[1] / coeff, // coeff = 1 for airborne location
-                    cpr_lon[1],
-                ),
-                _ => (
-                    *[nl[0] / coeff, 1].iter().max().unwrap(), // coeff = 4 for ground location
-                    nl[0] / coeff,                             // coeff = 1 for airborne location
-                    cpr_lon[0],
-                ),
-            };
-            let dlngt = 360.0 / ni as f64;
-            let m = (((cpr_lon[0] as f64 * (nlt - 1) as f64 - cpr_lon[1] as f64 * nlt as f64)
-                / div)
-                + 0.5)
-                .floor();
-            let lon = dlngt * (pmod(m as i32, ni) as f64 + lngt as f64 / div) as f64;
-
+            let lon = global_lon(cpr_lon, cpr_form, nl[0], 360.0);
             Some((rlat[cpr_form as usize], signed_lon(lon)))
         }
         false => None,
     }
 }
 
+/// Decodes an even and odd surface CPR frame pair (globally unambiguous decoding).
+///
+/// Surface CPR zones are four times smaller than the airborne ones, so the pair resolves
+/// the position within a 90 degree quadrant only. The quadrant closest to the reference
+/// (the observer or the last airborne position) is taken.
+///
+/// # Arguments
+///
+/// * `cpr_lat` - The CPR encoded even and odd latitudes.
+/// * `cpr_lon` - The CPR encoded even and odd longitudes.
+/// * `cpr_form` - The CPR format of the most recent frame, 0 - even, 1 - odd.
+/// * `reference` - The reference latitude and longitude.
+pub(crate) fn cpr_location_surface(
+    cpr_lat: &[u32; 2],
+    cpr_lon: &[u32; 2],
+    cpr_form: u32,
+    reference: (f64, f64),
+) -> Option<(f64, f64)> {
+    let div = (1 << 17) as f64;
+    let adl = [90.0 / 60.0, 90.0 / 59.0];
+    let form = cpr_form as usize;
+
+    let j = ((59.0 * cpr_lat[0] as f64 - 60.0 * cpr_lat[1] as f64) / div + 0.5).floor() as i32;
+    let rlat = [
+        adl[0] * (pmod(j, 60) as f64 + cpr_lat[0] as f64 / div),
+        adl[1] * (pmod(j, 59) as f64 + cpr_lat[1] as f64 / div),
+    ];
+    // northern solution, or the southern one 90 degrees below
+    let lat = [rlat[form], rlat[form] - 90.0]
+        .into_iter()
+        .min_by(|a, b| (a - reference.0).abs().total_cmp(&(b - reference.0).abs()))?;
+
+    let nl_lat = nl(lat);
+    if nl_lat != nl(rlat[1 - form] - (rlat[form] - lat)) {
+        return None;
+    }
+    let lon = global_lon(cpr_lon, cpr_form, nl_lat, 90.0);
+    let lon = (0..4)
+        .map(|quadrant| signed_lon(lon + 90.0 * quadrant as f64))
+        .min_by(|a, b| lon_distance(*a, reference.1).total_cmp(&lon_distance(*b, reference.1)))?;
+
+    Some((lat, lon))
+}
+
+/// Longitude of the pair within the first zone set of the given size (360 or 90 degrees).
+fn global_lon(cpr_lon: &[u32; 2], cpr_form: u32, nl: i32, zones: f64) -> f64 {
+    let div = (1 << 17) as f64;
+    let ni = *[nl - cpr_form as i32, 1].iter().max().unwrap();
+    let dlngt = zones / ni as f64;
+    let m = (((cpr_lon[0] as f64 * (nl - 1) as f64 - cpr_lon[1] as f64 * nl as f64) / div) + 0.5)
+        .floor();
+    dlngt * (pmod(m as i32, ni) as f64 + cpr_lon[cpr_form as usize] as f64 / div)
+}
+
+fn lon_distance(a: f64, b: f64) -> f64 {
+    let d = (a - b).abs() % 360.0;
+    d.min(360.0 - d)
+}
+
+/// Decodes a single CPR frame against a reference position (locally unambiguous decoding).
+///
+/// # Arguments
+///
+/// * `cpr_lat` - The CPR encoded latitude of the frame.
+/// * `cpr_lon` - The CPR encoded longitude of the frame.
+/// * `cpr_form` - The CPR format of the frame, 0 - even, 1 - odd.
+/// * `reference` - The reference latitude and longitude, within half a zone of the plane.
+/// * `coeff` - 1 for airborne, 4 for surface positions.
+///
+/// # Returns
+///
+/// The latitude and longitude of the zone closest to the reference.
+pub(crate) fn cpr_location_local(
+    cpr_lat: u32,
+    cpr_lon: u32,
+    cpr_form: u32,
+    reference: (f64, f64),
+    coeff: i32,
+) -> Option<(f64, f64)> {
+    let div = (1 << 17) as f64;
+    let (ref_lat, ref_lon) = reference;
+    let i = cpr_form as i32;
+
+    let dlat = 360.0 / coeff as f64 / (60 - i) as f64;
+    let yz = cpr_lat as f64 / div;
+    let j = (ref_lat / dlat).floor() + (0.5 + ref_lat.rem_euclid(dlat) / dlat - yz).floor();
+    let lat = dlat * (j + yz);
+    if !(-90.0..=90.0).contains(&lat) {
+        return None;
+    }
+
+    let dlon = 360.0 / coeff as f64 / *[nl(lat) - i, 1].iter().max().unwrap() as f64;
+    let xz = cpr_lon as f64 / div;
+    let m = (ref_lon / dlon).floor() + (0.5 + ref_lon.rem_euclid(dlon) / dlon - xz).floor();
+    let lon = dlon * (m + xz);
+
+    Some((lat, signed_lon(lon)))
+}
+
 fn signed_lon(lon: f64) -> f64 {
     match lon {
         180.0.. => lon - 360.0,
@@ -160,6 +240,46 @@ fn nl(lat: f64) -> i32 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_cpr_location_local() {
+        // 8D40621D58C382D690C8AC2863A7, even frame
+        let (lat, lon) = cpr_location_local(93000, 51372, 0, (52.258, 3.918), 1).unwrap();
+        assert!((lat - 52.25720).abs() < 1e-4, "{}", lat);
+        assert!((lon - 3.91937).abs() < 1e-4, "{}", lon);
+
+        // 8D40621D58C386435CC412692AD6, odd frame
+        let (lat, lon) = cpr_location_local(74158, 50194, 1, (52.258, 3.918), 1).unwrap();
+        assert!((lat - 52.26578).abs() < 1e-4, "{}", lat);
+        assert!((lon - 3.93891).abs() < 1e-4, "{}", lon);
+    }
+
+    #[test]
+    fn test_cpr_location() {
+        // 8D40621D58C382D690C8AC2863A7 even, 8D40621D58C386435CC412692AD6 odd
+        let (lat, lon) = cpr_location(&[93000, 74158], &[51372, 50194], 0).unwrap();
+        assert!((lat - 52.25720).abs() < 1e-4, "{}", lat);
+        assert!((lon - 3.91937).abs() < 1e-4, "{}", lon);
+    }
+
+    #[test]
+    fn test_cpr_location_surface() {
+        // 8C4841753AAB238733C8CD4020B1 even, 8C4841753A8A35323FAEBDAC702D odd, received last
+        let cpr_lat = [115609, 39199];
+        let cpr_lon = [116941, 110269];
+        let (lat, lon) = cpr_location_surface(&cpr_lat, &cpr_lon, 1, (51.990, 4.375)).unwrap();
+        assert!((lat - 52.32061).abs() < 1e-4, "{}", lat);
+        assert!((lon - 4.73473).abs() < 1e-4, "{}", lon);
+
+        // the same pair resolved in the quadrant of a reference in the southern hemisphere
+        let (lat, lon) = cpr_location_surface(&cpr_lat, &cpr_lon, 1, (-37.7, 175.0)).unwrap();
+        assert!((lat - (52.32061 - 90.0)).abs() < 1e-4, "{}", lat);
+        assert!(lon_distance(lon, 175.0) < 45.0, "{}", lon);
+
+        let (lat, lon) = cpr_location_local(39199, 110269, 1, (51.990, 4.375), 4).unwrap();
+        assert!((lat - 52.32061).abs() < 1e-4, "{}", lat);
+        assert!((lon - 4.73473).abs() < 1e-4, "{}", lon);
+    }
+
     #[test]
     fn test_pmod() {
         let x = -5;
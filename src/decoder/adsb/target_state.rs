@@ -0,0 +1,137 @@
+use crate::decoder::{flag_and_range_value, range_value};
+
+/// Autopilot modes reported by the version 2 target state and status message.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AutopilotModes {
+    pub autopilot: bool,
+    pub vnav: bool,
+    pub altitude_hold: bool,
+    pub approach: bool,
+    pub lnav: bool,
+}
+
+/// ADS-B target state and status message (TC 29).
+///
+/// `target_altitude_source` uses the BDS 4,0 coding: 1 - aircraft (holding) altitude,
+/// 2 - MCP/FCU selected altitude, 3 - FMS selected altitude.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TargetState {
+    pub selected_altitude: Option<u32>,
+    pub target_altitude_source: Option<u32>,
+    pub barometric_pressure_setting: Option<u32>,
+    pub selected_heading: Option<u32>,
+    pub autopilot_modes: Option<AutopilotModes>,
+    pub tcas_operational: Option<bool>,
+    pub nacp: Option<u32>,
+    pub sil: Option<u32>,
+}
+
+/// Decodes the target state and status message, subtype 0 (version 1) or 1 (version 2).
+pub(crate) fn target_state(message: &[u32]) -> Option<TargetState> {
+    match range_value(message, 38, 39)? {
+        0 => Some(target_state_v1(message)),
+        1 => Some(target_state_v2(message)),
+        _ => None,
+    }
+}
+
+fn flag(message: &[u32], bit: u32) -> bool {
+    range_value(message, bit, bit) == Some(1)
+}
+
+fn target_state_v1(message: &[u32]) -> TargetState {
+    let target_altitude_source = match range_value(message, 40, 41) {
+        Some(1) => Some(2),
+        Some(2) => Some(1),
+        Some(3) => Some(3),
+        _ => None,
+    };
+    TargetState {
+        selected_altitude: target_altitude_source
+            .and(range_value(message, 48, 57))
+            .and_then(|v| (v * 100).checked_sub(1000)),
+        target_altitude_source,
+        barometric_pressure_setting: None,
+        selected_heading: range_value(message, 58, 59)
+            .filter(|&v| v != 0)
+            .and(range_value(message, 60, 68))
+            .filter(|&v| v < 360),
+        autopilot_modes: None,
+        tcas_operational: Some(!flag(message, 84)),
+        nacp: range_value(message, 72, 75),
+        sil: range_value(message, 77, 78),
+    }
+}
+
+fn target_state_v2(message: &[u32]) -> TargetState {
+    let selected_altitude = range_value(message, 42, 52)
+        .filter(|&v| v != 0)
+        .map(|v| (v - 1) * 32);
+    TargetState {
+        selected_altitude,
+        target_altitude_source: selected_altitude.map(|_| match flag(message, 41) {
+            true => 3,
+            false => 2,
+        }),
+        barometric_pressure_setting: range_value(message, 53, 61)
+            .filter(|&v| v != 0)
+            .map(|v| (800.0 + (v - 1) as f64 * 0.8).round() as u32),
+        selected_heading: flag_and_range_value(message, 63, 64, 71)
+            .filter(|_| flag(message, 62))
+            .map(|(sign, v)| (((sign << 8) | v) as f64 * 180.0 / 256.0).round() as u32 % 360),
+        autopilot_modes: match flag(message, 79) {
+            true => Some(AutopilotModes {
+                autopilot: flag(message, 80),
+                vnav: flag(message, 81),
+                altitude_hold: flag(message, 82),
+                approach: flag(message, 84),
+                lnav: flag(message, 86),
+            }),
+            false => None,
+        },
+        tcas_operational: Some(flag(message, 85)),
+        nacp: range_value(message, 72, 75),
+        sil: range_value(message, 77, 78),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::message;
+
+    #[test]
+    fn test_target_state_v2() {
+        if let Some(message) = message("8DA05629EA21485CBF3F8CADAEEB") {
+            let result = target_state(&message).unwrap();
+            assert_eq!(result.selected_altitude, Some(16992));
+            assert_eq!(result.target_altitude_source, Some(2));
+            assert_eq!(result.barometric_pressure_setting, Some(1013));
+            assert_eq!(result.selected_heading, Some(67));
+            assert_eq!(
+                result.autopilot_modes,
+                Some(AutopilotModes {
+                    autopilot: true,
+                    vnav: true,
+                    altitude_hold: false,
+                    approach: false,
+                    lnav: true,
+                })
+            );
+            assert_eq!(result.tcas_operational, Some(true));
+            assert_eq!(result.nacp, Some(9));
+            assert_eq!(result.sil, Some(3));
+        }
+    }
+
+    #[test]
+    fn test_target_state_v1() {
+        if let Some(message) = message("8D4CA13DE884BE26413C00A08E60") {
+            let result = target_state(&message).unwrap();
+            assert_eq!(result.selected_altitude, Some(37000));
+            assert_eq!(result.target_altitude_source, Some(2));
+            assert_eq!(result.selected_heading, Some(100));
+            assert_eq!(result.autopilot_modes, None);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::decoder::{flag_and_range_value, range_value};
+
+/// Retrieves the CPR format, latitude and longitude of the coarse TIS-B airborne position.
+///
+/// The coarse format encodes the position with 12 bits, the values are scaled up to
+/// the 17 bits of the fine format so that the common CPR decoding applies.
+pub(crate) fn coarse_cpr(message: &[u32]) -> Option<(u32, u32, u32)> {
+    flag_and_range_value(message, 64, 65, 76).and_then(|(cpr_form, cpr_lat)| {
+        range_value(message, 77, 88).map(|cpr_lon| (cpr_form, cpr_lat << 5, cpr_lon << 5))
+    })
+}
+
+/// Retrieves the ground track and ground speed of the coarse TIS-B airborne position.
+///
+/// The track comes in 11.25 degrees steps and the speed in 16 knots steps,
+/// both are present only when the track/heading valid bit is set.
+pub(crate) fn coarse_track_and_groundspeed(message: &[u32]) -> (Option<u32>, Option<u32>) {
+    match flag_and_range_value(message, 52, 53, 57) {
+        Some((1, track)) => (
+            Some((track as f64 * 11.25).round() as u32),
+            range_value(message, 58, 63).map(|value| value * 16),
+        ),
+        _ => (None, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::message;
+
+    #[test]
+    fn test_coarse_cpr() {
+        if let Some(message) = message("9340621D03871438B5A645743DAA") {
+            assert_eq!(coarse_cpr(&message), Some((0, 92992, 51360)));
+        }
+    }
+
+    #[test]
+    fn test_coarse_track_and_groundspeed() {
+        if let Some(message) = message("9340621D03871438B5A645743DAA") {
+            assert_eq!(
+                coarse_track_and_groundspeed(&message),
+                (Some(90), Some(448))
+            );
+        }
+    }
+}
@@ -1,6 +1,6 @@
 pub(crate) fn vertical_rate(message: &[u32]) -> Option<i32> {
     crate::decoder::flag_and_range_value(message, 69, 70, 78)
-        .filter(|&f| f.1 != 1)
+        .filter(|&f| f.1 != 0)
         .map(|(sign, value)| vertical_rate_value(sign, value))
 }
 
@@ -11,3 +11,20 @@ fn vertical_rate_value(sign: u32, value: u32) -> i32 {
         _ => value,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::message;
+
+    #[test]
+    fn test_vertical_rate() {
+        let vrate =
+            message("8D485020994409940838175B284F").and_then(|message| vertical_rate(&message));
+        assert_eq!(vrate, Some(-832));
+        // VR 0, no vertical rate information
+        let vrate =
+            message("8D48502099440994080017F5D846").and_then(|message| vertical_rate(&message));
+        assert_eq!(vrate, None);
+    }
+}
@@ -0,0 +1,124 @@
+use crate::decoder;
+
+/// Data link capability report (BDS 1,0).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DataLinkCapability {
+    /// The next register (BDS 1,1) holds the continuation of the report.
+    pub continuation: bool,
+    pub overlay_command: bool,
+    pub acas_operational: bool,
+    pub subnetwork_version: u32,
+    /// Enhanced protocol indicator, a Level 5 transponder.
+    pub enhanced_protocol: bool,
+    pub specific_services: bool,
+    /// Uplink ELM average throughput capability, 0 without uplink ELM.
+    pub uplink_elm: u32,
+    /// Downlink ELM throughput capability, 0 without downlink ELM.
+    pub downlink_elm: u32,
+    pub aircraft_identification: bool,
+    pub squitter: bool,
+    pub surveillance_identifier: bool,
+    /// The common usage GICB capability report (BDS 1,7) has changed.
+    pub common_usage_gicb: bool,
+    pub hybrid_surveillance: bool,
+    /// ACAS generates resolution advisories, traffic advisories only otherwise.
+    pub acas_resolution_advisories: bool,
+    /// RTCA DO-185 version of the ACAS, 0 - DO-185, 1 - DO-185A, 2 - DO-185B.
+    pub acas_version: u32,
+    /// Data terminal equipment status, one bit per subaddress.
+    pub dte_status: u32,
+}
+
+impl DataLinkCapability {
+    /// Transponder level, 5 with the enhanced protocol, 4 with downlink ELM,
+    /// 3 with uplink ELM only and 2 with Comm-A/B only.
+    pub fn transponder_level(&self) -> u32 {
+        match (self.enhanced_protocol, self.downlink_elm, self.uplink_elm) {
+            (true, _, _) => 5,
+            (false, 1.., _) => 4,
+            (false, 0, 1..) => 3,
+            _ => 2,
+        }
+    }
+}
+
+/// Decodes the data link capability report.
+///
+/// The overlay command capability comes with the subnetwork version 5 and later,
+/// a report breaking this rule is not a BDS 1,0.
+pub fn is_bds_1_0(message: &[u32]) -> Option<DataLinkCapability> {
+    let flag = |bit: u32| decoder::range_value(message, bit, bit).map(|value| value == 1);
+    if decoder::range_value(message, 33, 40)? != 0x10 || decoder::range_value(message, 42, 46)? != 0
+    {
+        return None;
+    }
+    let overlay_command = flag(47)?;
+    let subnetwork_version = decoder::range_value(message, 49, 55)?;
+    if overlay_command != (subnetwork_version >= 5) {
+        return None;
+    }
+    Some(DataLinkCapability {
+        continuation: flag(41)?,
+        overlay_command,
+        acas_operational: flag(48)?,
+        subnetwork_version,
+        enhanced_protocol: flag(56)?,
+        specific_services: flag(57)?,
+        uplink_elm: decoder::range_value(message, 58, 60)?,
+        downlink_elm: decoder::range_value(message, 61, 64)?,
+        aircraft_identification: flag(65)?,
+        squitter: flag(66)?,
+        surveillance_identifier: flag(67)?,
+        common_usage_gicb: flag(68)?,
+        hybrid_surveillance: flag(69)?,
+        acas_resolution_advisories: flag(70)?,
+        acas_version: decoder::range_value(message, 71, 72)?,
+        dte_status: decoder::range_value(message, 73, 88)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::message;
+
+    #[test]
+    fn test_is_bds_1_0() {
+        let capability = message("A800178D10030B80F50000D5893C")
+            .and_then(|message| is_bds_1_0(&message))
+            .unwrap();
+        assert_eq!(capability.subnetwork_version, 5);
+        assert!(capability.overlay_command);
+        assert!(capability.acas_operational);
+        assert!(capability.specific_services);
+        assert!(capability.aircraft_identification);
+        assert!(capability.squitter);
+        assert!(capability.surveillance_identifier);
+        assert!(capability.common_usage_gicb);
+        assert!(capability.acas_resolution_advisories);
+        assert_eq!(capability.acas_version, 1);
+        assert_eq!(capability.transponder_level(), 5);
+
+        assert_eq!(
+            message("A800161110010080E6000073D501").and_then(|message| is_bds_1_0(&message)),
+            Some(DataLinkCapability {
+                acas_operational: true,
+                specific_services: true,
+                aircraft_identification: true,
+                squitter: true,
+                surveillance_identifier: true,
+                acas_resolution_advisories: true,
+                acas_version: 2,
+                ..DataLinkCapability::default()
+            })
+        );
+        assert_eq!(
+            message("A800178D10030080F50000D5893C").and_then(|message| is_bds_1_0(&message)),
+            None
+        );
+        assert_eq!(
+            message("A000029C85E42F313000007047D3").and_then(|message| is_bds_1_0(&message)),
+            None
+        );
+    }
+}
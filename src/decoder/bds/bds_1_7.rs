@@ -1,5 +1,35 @@
 use crate::decoder;
 
+/// Registers of the common usage GICB capability report, one bit each from the MSB.
+const GICB_REGISTERS: [(u32, u32); 24] = [
+    (0, 5),
+    (0, 6),
+    (0, 7),
+    (0, 8),
+    (0, 9),
+    (0, 10),
+    (2, 0),
+    (2, 1),
+    (4, 0),
+    (4, 1),
+    (4, 2),
+    (4, 3),
+    (4, 4),
+    (4, 5),
+    (4, 8),
+    (5, 0),
+    (5, 1),
+    (5, 2),
+    (5, 3),
+    (5, 4),
+    (5, 5),
+    (5, 6),
+    (5, 15),
+    (6, 0),
+];
+
+/// Common usage GICB capability report (BDS 1,7).
+#[derive(Debug, Clone, Copy)]
 pub struct Capability {
     pub flags: u32,
     pub bds20: bool,
@@ -38,6 +68,23 @@ impl Capability {
             bds60,
         }
     }
+
+    /// The register is announced by the capability report.
+    pub fn supports(&self, bds: (u32, u32)) -> bool {
+        GICB_REGISTERS
+            .iter()
+            .position(|&register| register == bds)
+            .is_some_and(|bit| (self.flags >> (23 - bit)) & 1 == 1)
+    }
+
+    /// All the registers announced by the capability report.
+    pub fn registers(&self) -> Vec<(u32, u32)> {
+        GICB_REGISTERS
+            .iter()
+            .filter(|&&bds| self.supports(bds))
+            .copied()
+            .collect()
+    }
 }
 
 impl Default for Capability {
@@ -66,3 +113,20 @@ pub fn is_bds_1_7(message: &[u32]) -> Option<Capability> {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::message;
+
+    #[test]
+    fn test_is_bds_1_7() {
+        let capability = message("A000000002810100000000000000")
+            .and_then(|message| is_bds_1_7(&message))
+            .unwrap();
+        assert_eq!(capability.registers(), vec![(2, 0), (4, 0), (5, 0), (6, 0)]);
+        assert!(capability.bds40 && capability.bds50 && capability.bds60);
+        assert!(!capability.supports((4, 4)));
+        assert!(!capability.supports((1, 7)));
+    }
+}
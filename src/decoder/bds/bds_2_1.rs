@@ -0,0 +1,95 @@
+use crate::decoder;
+
+/// Aircraft and airline registration markings (BDS 2,1).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Registration {
+    pub aircraft: Option<String>,
+    /// ICAO airline registration marking.
+    pub airline: Option<String>,
+}
+
+/// Converts a 6-bit character code to a `char`, `None` for the codes outside
+/// the letters, digits and space.
+fn character(code: u32) -> Option<char> {
+    match code {
+        1..=26 => char::from_u32(code | 64),
+        32 | 48..=57 => char::from_u32(code),
+        _ => None,
+    }
+}
+
+/// Decodes a marking of 6-bit characters starting at bit `sb`.
+///
+/// The marking starts with a letter or digit and may only be padded with trailing spaces.
+fn marking(message: &[u32], sb: u32, length: u32) -> Option<String> {
+    let mut marking = (0..length)
+        .map(|i| decoder::range_value(message, sb + i * 6, sb + i * 6 + 5).and_then(character))
+        .collect::<Option<String>>()?;
+    marking.truncate(marking.trim_end().len());
+    match marking.contains(' ') || marking.len() < 2 {
+        true => None,
+        false => Some(marking),
+    }
+}
+
+/// Decodes a marking with its status bit, the marking must be empty when the status is not set.
+fn field(message: &[u32], status: u32, length: u32) -> Option<Option<String>> {
+    match decoder::range_value(message, status, status)? {
+        0 => match decoder::range_value(message, status + 1, status + length * 6)? {
+            0 => Some(None),
+            _ => None,
+        },
+        _ => marking(message, status + 1, length).map(Some),
+    }
+}
+
+/// Decodes the aircraft (bits 34-75) and airline (bits 77-88) registration markings.
+///
+/// At least one of the status bits must be set.
+pub fn is_bds_2_1(message: &[u32]) -> Option<Registration> {
+    let aircraft = field(message, 33, 7)?;
+    let airline = field(message, 76, 2)?;
+    match (&aircraft, &airline) {
+        (None, None) => None,
+        _ => Some(Registration { aircraft, airline }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::message;
+
+    #[test]
+    fn test_is_bds_2_1() {
+        assert_eq!(
+            message("A00018388A4882830411491DB2D6").and_then(|message| is_bds_2_1(&message)),
+            Some(Registration {
+                aircraft: Some("EIDEA".to_string()),
+                airline: Some("EI".to_string()),
+            })
+        );
+        assert_eq!(
+            message("A00018389D8E5982140000596115").and_then(|message| is_bds_2_1(&message)),
+            Some(Registration {
+                aircraft: Some("N123AB".to_string()),
+                airline: None,
+            })
+        );
+        assert_eq!(
+            message("A0001838000000000010815B79F5").and_then(|message| is_bds_2_1(&message)),
+            Some(Registration {
+                aircraft: None,
+                airline: Some("BA".to_string()),
+            })
+        );
+        assert_eq!(
+            message("A0001838300000000000007ADA59").and_then(|message| is_bds_2_1(&message)),
+            None
+        );
+        assert_eq!(
+            message("A00004128F39F91A7E27C46ADC21").and_then(|message| is_bds_2_1(&message)),
+            None
+        );
+    }
+}
@@ -2,11 +2,20 @@ use log::debug;
 
 use crate::decoder;
 
+/// MCP/FCU mode bits of the selected vertical intention.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct McpModes {
+    pub vnav: bool,
+    pub altitude_hold: bool,
+    pub approach: bool,
+}
+
 #[derive(Debug)]
 pub struct SelectedVerticalIntention {
     pub mcp_selected_altitude: Option<u32>,
     pub fms_selected_altitude: Option<u32>,
     pub barometric_pressure_setting: Option<u32>,
+    pub mcp_modes: Option<McpModes>,
     pub target_altitude_source: Option<u32>,
 }
 
@@ -16,6 +25,7 @@ impl SelectedVerticalIntention {
             mcp_selected_altitude: None,
             fms_selected_altitude: None,
             barometric_pressure_setting: None,
+            mcp_modes: None,
             target_altitude_source: None,
         }
     }
@@ -24,12 +34,14 @@ impl SelectedVerticalIntention {
         mcp_selected_altitude: Option<u32>,
         fms_selected_altitude: Option<u32>,
         barometric_pressure_setting: Option<u32>,
+        mcp_modes: Option<McpModes>,
         target_altitude_source: Option<u32>,
     ) -> Self {
         SelectedVerticalIntention {
             mcp_selected_altitude,
             fms_selected_altitude,
             barometric_pressure_setting,
+            mcp_modes,
             target_altitude_source,
         }
     }
@@ -46,13 +58,14 @@ pub fn is_bds_4_0(message: &[u32]) -> Option<SelectedVerticalIntention> {
         && decoder::goodflags(message, 46, 47, 58)
         && decoder::goodflags(message, 59, 60, 71)
         && !decoder::goodflags(message, 33, 72, 79)
-        && !decoder::goodflags(message, 33, 84, 85)
+        && decoder::unused_bits_clear(message)
     {
         let intent = SelectedVerticalIntention::from_data(
             decoder::mcp_selected_altitude(message).filter(|x| (0..=65530).contains(x)),
             decoder::fms_selected_altitude(message).filter(|x| (0..=65530).contains(x)),
             decoder::barometric_pressure_setting(message).filter(|x| (800..=1210).contains(x)),
-            decoder::target_altitude_source(message).filter(|x| (0..=3).contains(x)),
+            decoder::mcp_modes(message),
+            decoder::target_altitude_source(message),
         );
         debug!("BDS:4.0 {:?}", intent);
         if intent.mcp_selected_altitude.is_some() || intent.fms_selected_altitude.is_some() {
@@ -1,9 +1,45 @@
 use log::debug;
+use std::fmt::{self, Display};
 
 use crate::decoder;
 
-#[derive(Debug)]
+/// Source of the wind and temperature data, the figure of merit of the report.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MeteoSource {
+    Ins,
+    Gnss,
+    DmeDme,
+    VorDme,
+}
+
+impl MeteoSource {
+    fn from_fom(fom: u32) -> Option<Self> {
+        match fom {
+            1 => Some(MeteoSource::Ins),
+            2 => Some(MeteoSource::Gnss),
+            3 => Some(MeteoSource::DmeDme),
+            4 => Some(MeteoSource::VorDme),
+            _ => None,
+        }
+    }
+}
+
+impl Display for MeteoSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MeteoSource::Ins => write!(f, "INS"),
+            MeteoSource::Gnss => write!(f, "GNSS"),
+            MeteoSource::DmeDme => write!(f, "DME/DME"),
+            MeteoSource::VorDme => write!(f, "VOR/DME"),
+        }
+    }
+}
+
+/// Meteorological routine air report (BDS 4,4), `None` for the fields the report
+/// does not mark as valid.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Meteo {
+    pub source: Option<MeteoSource>,
     pub temp: Option<f64>,
     pub wind: Option<(u32, u32)>,
     pub humidity: Option<u32>,
@@ -14,6 +50,7 @@ pub struct Meteo {
 impl Meteo {
     pub fn new() -> Self {
         Meteo {
+            source: None,
             temp: None,
             wind: None,
             humidity: None,
@@ -23,6 +60,7 @@ impl Meteo {
     }
 
     pub fn from_data(
+        source: Option<MeteoSource>,
         temp: Option<f64>,
         wind: Option<(u32, u32)>,
         humidity: Option<u32>,
@@ -30,6 +68,7 @@ impl Meteo {
         pressure: Option<u32>,
     ) -> Self {
         Meteo {
+            source,
             temp,
             wind,
             humidity,
@@ -45,39 +84,99 @@ impl Default for Meteo {
     }
 }
 
+/// The bits of a field must be zero when its status is not set.
+fn clear_without_status(message: &[u32], status: u32, sb: u32, eb: u32) -> bool {
+    matches!(
+        decoder::flag_and_range_value(message, status, sb, eb),
+        Some((1, _)) | Some((0, 0))
+    )
+}
+
+/// Decodes the meteorological routine air report.
+///
+/// The report needs a valid source (FOM 1-4), zero bits for the fields without status and
+/// a temperature in the range of the atmosphere. Partial reports are kept, an empty
+/// register is not a report.
 pub fn is_bds_4_4(message: &[u32]) -> Option<Meteo> {
-    let fom = decoder::range_value(message, 33, 36).unwrap();
-    if fom > 0b1000
-        && decoder::goodflags(message, 37, 38, 55)
-        && decoder::goodflags(message, 37, 57, 66)
-        && decoder::goodflags(message, 67, 68, 78)
-        && decoder::goodflags(message, 79, 80, 81)
-        && decoder::goodflags(message, 82, 83, 88)
+    let df = decoder::df(message)?;
+    let fom = decoder::fom_4_4(message)?;
+    let source = MeteoSource::from_fom(fom)?;
+    if !(clear_without_status(message, 37, 38, 55)
+        && clear_without_status(message, 67, 68, 78)
+        && clear_without_status(message, 79, 80, 81)
+        && clear_without_status(message, 82, 83, 88))
     {
-        let meteo = Meteo::from_data(
-            decoder::temperature_4_4(message).filter(|x| (-80.0..=60.0).contains(x)),
-            decoder::wind_4_4(message).filter(|x| (0..=300).contains(&x.0)),
-            decoder::humidity_4_4(message).filter(|x| (0..=100).contains(x)),
-            decoder::turbulence_4_4(message).filter(|x| (0..=15).contains(x)),
-            decoder::pressure_4_4(message).filter(|x| (0..=2048).contains(x)),
+        return None;
+    }
+    let meteo = Meteo::from_data(
+        Some(source),
+        decoder::temperature_4_4(message),
+        decoder::wind_4_4(message),
+        decoder::humidity_4_4(message),
+        decoder::turbulence_4_4(message),
+        decoder::pressure_4_4(message),
+    );
+    debug!("DF:{} F:{:b} {:?}", df, fom, meteo);
+    let empty = meteo.temp == Some(0.0)
+        && meteo.wind.is_none()
+        && meteo.humidity.is_none()
+        && meteo.turbulence.is_none()
+        && meteo.pressure.is_none();
+    if empty
+        || !meteo.temp.is_some_and(|x| (-80.0..=60.0).contains(&x))
+        || meteo.wind.is_some_and(|x| x.0 > 300)
+        || meteo.humidity.is_some_and(|x| x > 100)
+    {
+        None
+    } else {
+        Some(meteo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::message;
+
+    #[test]
+    fn test_is_bds_4_4() {
+        assert_eq!(
+            message("A000183828B701CE0002803DA3E1").and_then(|message| is_bds_4_4(&message)),
+            Some(Meteo::from_data(
+                Some(MeteoSource::Gnss),
+                Some(-50.0),
+                Some((45, 270)),
+                None,
+                Some(1),
+                None
+            ))
         );
-        debug!(
-            "DF:{} F:{:b} {:?}",
-            decoder::df(message).unwrap(),
-            decoder::range_value(message, 33, 36).unwrap(),
-            meteo
+        assert_eq!(
+            message("A00018381000000F2FD4605D6B37").and_then(|message| is_bds_4_4(&message)),
+            Some(Meteo::from_data(
+                Some(MeteoSource::Ins),
+                Some(15.0),
+                None,
+                Some(50),
+                None,
+                Some(1013)
+            ))
         );
-        if meteo.temp.is_some()
-            // && meteo.wind.is_some()
-            && meteo.humidity.is_some()
-            && meteo.turbulence.is_some()
-            && meteo.pressure.is_some()
-        {
-            Some(meteo)
-        } else {
+        assert_eq!(
+            message("A000183898B701CE0002804AFB95").and_then(|message| is_bds_4_4(&message)),
             None
-        }
-    } else {
-        None
+        );
+        assert_eq!(
+            message("A000183820B701CE000280DE5074").and_then(|message| is_bds_4_4(&message)),
+            None
+        );
+        assert_eq!(
+            message("A800132C30000000000000C2BCA1").and_then(|message| is_bds_4_4(&message)),
+            None
+        );
+        assert_eq!(
+            message("A0001718E8CA0530E00FFF9FDC52").and_then(|message| is_bds_4_4(&message)),
+            None
+        );
     }
 }
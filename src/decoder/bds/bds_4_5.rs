@@ -1,25 +1,172 @@
 use crate::decoder;
+use std::fmt::{self, Display};
 
-pub(crate) fn is_bds_4_5(message: &[u32]) -> Option<f64> {
-    if decoder::goodflags(message, 33, 34, 35)
-        && decoder::goodflags(message, 36, 37, 38)
-        && decoder::goodflags(message, 39, 40, 41)
-        && decoder::goodflags(message, 42, 43, 44)
-        && decoder::goodflags(message, 45, 46, 47)
-        && decoder::goodflags(message, 48, 49, 58)
-        && decoder::goodflags(message, 59, 60, 60)
-        && decoder::goodflags(message, 71, 72, 83)
-        && !decoder::goodflags(message, 33, 84, 88)
+/// Hazard level of the meteorological hazard report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HazardLevel {
+    Nil,
+    Light,
+    Moderate,
+    Severe,
+}
+
+impl HazardLevel {
+    fn from_value(value: u32) -> Self {
+        match value {
+            0 => HazardLevel::Nil,
+            1 => HazardLevel::Light,
+            2 => HazardLevel::Moderate,
+            _ => HazardLevel::Severe,
+        }
+    }
+}
+
+impl Display for HazardLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HazardLevel::Nil => write!(f, "Nil"),
+            HazardLevel::Light => write!(f, "Light"),
+            HazardLevel::Moderate => write!(f, "Moderate"),
+            HazardLevel::Severe => write!(f, "Severe"),
+        }
+    }
+}
+
+/// Meteorological hazard report (BDS 4,5).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Hazard {
+    pub turbulence: Option<HazardLevel>,
+    pub wind_shear: Option<HazardLevel>,
+    pub microburst: Option<HazardLevel>,
+    pub icing: Option<HazardLevel>,
+    pub wake_vortex: Option<HazardLevel>,
+    /// Static air temperature in Celsius.
+    pub temperature: Option<f64>,
+    /// Average static pressure in hPa.
+    pub pressure: Option<u32>,
+    /// Radio height in feet.
+    pub radio_height: Option<u32>,
+}
+
+impl Hazard {
+    /// Turbulence, wind shear, microburst, icing and wake vortex levels.
+    pub fn levels(&self) -> [Option<HazardLevel>; 5] {
+        [
+            self.turbulence,
+            self.wind_shear,
+            self.microburst,
+            self.icing,
+            self.wake_vortex,
+        ]
+    }
+
+    /// Any hazard reported above nil.
+    pub fn is_hazardous(&self) -> bool {
+        self.levels()
+            .iter()
+            .any(|level| level.is_some_and(|level| level > HazardLevel::Nil))
+    }
+}
+
+/// The hazard levels followed by the temperature, pressure and radio height,
+/// empty columns for the values not reported.
+impl Display for Hazard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let levels = self
+            .levels()
+            .iter()
+            .map(|level| level.map(|level| level.to_string()).unwrap_or_default())
+            .collect::<Vec<_>>();
+        write!(
+            f,
+            "{},{},{},{}",
+            levels.join(","),
+            self.temperature.map(|v| v.to_string()).unwrap_or_default(),
+            self.pressure.map(|v| v.to_string()).unwrap_or_default(),
+            self.radio_height.map(|v| v.to_string()).unwrap_or_default()
+        )
+    }
+}
+
+/// Decodes the value of a field with its status bit, the value must be zero when the
+/// status is not set.
+fn field(message: &[u32], status: u32, sb: u32, eb: u32) -> Option<Option<u32>> {
+    match decoder::flag_and_range_value(message, status, sb, eb)? {
+        (0, 0) => Some(None),
+        (0, _) => None,
+        (_, value) => Some(Some(value)),
+    }
+}
+
+/// Decodes the meteorological hazard report.
+///
+/// The report needs at least one field, zero reserved bits and a temperature and
+/// pressure in the range of the atmosphere.
+pub fn is_bds_4_5(message: &[u32]) -> Option<Hazard> {
+    if decoder::range_value(message, 84, 88)? != 0 {
+        return None;
+    }
+    let level = |status: u32| {
+        field(message, status, status + 1, status + 2)
+            .map(|value| value.map(HazardLevel::from_value))
+    };
+    let hazard = Hazard {
+        turbulence: level(33)?,
+        wind_shear: level(36)?,
+        microburst: level(39)?,
+        icing: level(42)?,
+        wake_vortex: level(45)?,
+        temperature: field(message, 48, 49, 58)?.map(decoder::temp_4_5),
+        pressure: field(message, 59, 60, 70)?,
+        radio_height: field(message, 71, 72, 83)?.map(|value| value * 16),
+    };
+    if hazard == Hazard::default()
+        || hazard
+            .temperature
+            .is_some_and(|temp| !(-80.0..=60.0).contains(&temp))
+        || hazard.pressure.is_some_and(|pressure| pressure > 1100)
     {
-        decoder::temperature_4_5(message).filter(|&temp| temp <= 45.0)
-    } else {
-        None
-    }
-}
-//#[cfg(test)]
-//mod tests {
-//    use super::*;
-//
-//    #[test]
-//    fn test_is_bds_4_5()
-//}
+        return None;
+    }
+    Some(hazard)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::message;
+
+    #[test]
+    fn test_is_bds_4_5() {
+        let hazard = message("A0001838D051CE00000000E1D71E")
+            .and_then(|message| is_bds_4_5(&message))
+            .unwrap();
+        assert_eq!(hazard.turbulence, Some(HazardLevel::Moderate));
+        assert_eq!(hazard.wind_shear, Some(HazardLevel::Nil));
+        assert_eq!(hazard.microburst, None);
+        assert_eq!(hazard.icing, Some(HazardLevel::Light));
+        assert_eq!(hazard.wake_vortex, None);
+        assert_eq!(hazard.temperature, Some(-50.0));
+        assert_eq!(hazard.pressure, None);
+        assert!(hazard.is_hazardous());
+        assert_eq!(hazard.to_string(), "Moderate,Nil,,Light,,-50,,");
+
+        assert_eq!(
+            message("A000183800010CAFD60960BD654F").and_then(|message| is_bds_4_5(&message)),
+            Some(Hazard {
+                temperature: Some(12.5),
+                pressure: Some(1013),
+                radio_height: Some(1200),
+                ..Hazard::default()
+            })
+        );
+        assert_eq!(
+            message("A0001838300000000000007ADA59").and_then(|message| is_bds_4_5(&message)),
+            None
+        );
+        assert_eq!(
+            message("A00004128F39F91A7E27C46ADC21").and_then(|message| is_bds_4_5(&message)),
+            None
+        );
+    }
+}
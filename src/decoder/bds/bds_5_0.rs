@@ -1,6 +1,7 @@
 use super::goodflags;
 use crate::decoder::*;
 
+#[derive(Debug)]
 pub struct TrackAndTurn {
     pub roll_angle: Option<i32>,
     pub track_angle: Option<u32>,
@@ -1,5 +1,6 @@
 use super::goodflags;
 use crate::decoder::*;
+#[derive(Debug)]
 pub struct HeadingAndSpeed {
     pub magnetic_heading: Option<u32>,
     pub indicated_airspeed: Option<u32>,
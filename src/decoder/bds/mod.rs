@@ -1,11 +1,15 @@
+mod bds_1_0;
 mod bds_1_7;
+mod bds_2_1;
 mod bds_4_0;
 mod bds_4_4;
 mod bds_4_5;
 mod bds_5_0;
 mod bds_6_0;
 
+pub(crate) use bds_1_0::*;
 pub(crate) use bds_1_7::*;
+pub(crate) use bds_2_1::*;
 pub(crate) use bds_4_0::*;
 pub(crate) use bds_4_4::*;
 pub(crate) use bds_4_5::*;
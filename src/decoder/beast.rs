@@ -0,0 +1,201 @@
+use crate::decoder::{frame_from_bytes, Frame};
+use log::{debug, warn};
+use std::io::{self, BufRead, Bytes};
+
+const ESCAPE: u8 = 0x1A;
+const TIMESTAMP_LEN: usize = 6;
+const SIGNAL_LEN: usize = 1;
+
+/// Reads Mode-S Beast binary frames from a byte stream.
+///
+/// Every frame starts with `0x1A` followed by the frame type, a 48-bit timestamp of
+/// the 12 MHz receiver clock, a signal level byte and the frame data. Any `0x1A`
+/// inside a frame is escaped by doubling it.
+///
+/// Only Mode S short (type '2') and long (type '3') frames are returned, Mode A/C
+/// (type '1') and unknown frames are skipped. DF17/DF18 frames are repaired up to
+/// `error_correction` bit errors.
+///
+/// # Examples
+///
+/// ```
+/// use squitterator::decoder::BeastReader;
+/// let stream: &[u8] = &[
+///     0x1A, b'2', 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x80,
+///     0x02, 0xE1, 0x97, 0xB0, 0x01, 0x79, 0xC3,
+/// ];
+/// let frames = BeastReader::new(stream, 1).collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(frames.len(), 1);
+/// assert_eq!(frames[0].timestamp, Some(1));
+/// assert_eq!(frames[0].signal, Some(0x80));
+/// ```
+pub struct BeastReader<R: BufRead> {
+    bytes: Bytes<R>,
+    frame_type: Option<u8>,
+    error_correction: u32,
+}
+
+impl<R: BufRead> BeastReader<R> {
+    pub fn new(reader: R, error_correction: u32) -> Self {
+        BeastReader {
+            bytes: reader.bytes(),
+            frame_type: None,
+            error_correction,
+        }
+    }
+
+    fn byte(&mut self) -> io::Result<Option<u8>> {
+        self.bytes.next().transpose()
+    }
+
+    /// Skips the stream up to the next frame start and returns the frame type.
+    fn sync(&mut self) -> io::Result<Option<u8>> {
+        loop {
+            match self.byte()? {
+                Some(ESCAPE) => match self.byte()? {
+                    Some(ESCAPE) => continue,
+                    frame_type => return Ok(frame_type),
+                },
+                Some(_) => continue,
+                None => return Ok(None),
+            }
+        }
+    }
+
+    /// Reads `len` unescaped bytes of the current frame.
+    ///
+    /// Returns `Err(frame_type)` when a new frame starts before the current one is complete.
+    fn body(&mut self, len: usize) -> io::Result<Option<Result<Vec<u8>, u8>>> {
+        let mut body = Vec::with_capacity(len);
+        while body.len() < len {
+            match self.byte()? {
+                Some(ESCAPE) => match self.byte()? {
+                    Some(ESCAPE) => body.push(ESCAPE),
+                    Some(frame_type) => return Ok(Some(Err(frame_type))),
+                    None => return Ok(None),
+                },
+                Some(b) => body.push(b),
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(Ok(body)))
+    }
+}
+
+impl<R: BufRead> Iterator for BeastReader<R> {
+    type Item = io::Result<Frame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let frame_type = match self.frame_type.take() {
+                Some(frame_type) => frame_type,
+                None => match self.sync() {
+                    Ok(Some(frame_type)) => frame_type,
+                    Ok(None) => return None,
+                    Err(e) => return Some(Err(e)),
+                },
+            };
+
+            let len = match frame_len(frame_type) {
+                Some(len) => len,
+                None => {
+                    debug!("Beast: skipping frame type {:02X}", frame_type);
+                    continue;
+                }
+            };
+
+            let body = match self.body(TIMESTAMP_LEN + SIGNAL_LEN + len) {
+                Ok(Some(Ok(body))) => body,
+                Ok(Some(Err(next_frame_type))) => {
+                    warn!("Beast: truncated frame type {:02X}", frame_type);
+                    self.frame_type = Some(next_frame_type);
+                    continue;
+                }
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            };
+
+            // Mode A/C replies have no place in the Mode S pipeline
+            if frame_type == b'1' {
+                continue;
+            }
+
+            let timestamp = body[..TIMESTAMP_LEN]
+                .iter()
+                .fold(0u64, |acc, &b| (acc << 8) | b as u64);
+            let signal = body[TIMESTAMP_LEN];
+            if let Some(frame) =
+                frame_from_bytes(&body[TIMESTAMP_LEN + SIGNAL_LEN..], self.error_correction)
+            {
+                return Some(Ok(Frame {
+                    timestamp: Some(timestamp),
+                    signal: Some(signal),
+                    ..frame
+                }));
+            }
+        }
+    }
+}
+
+fn frame_len(frame_type: u8) -> Option<usize> {
+    match frame_type {
+        b'1' => Some(2),
+        b'2' => Some(7),
+        b'3' => Some(14),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::message;
+
+    fn frames(stream: &[u8]) -> Vec<Frame> {
+        BeastReader::new(stream, 1)
+            .collect::<io::Result<Vec<Frame>>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_beast_long_frame() {
+        let stream = [
+            0x1A, b'3', 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x7F, 0x8D, 0x40, 0x62, 0x1D, 0x58,
+            0xC3, 0x82, 0xD6, 0x90, 0xC8, 0xAC, 0x28, 0x63, 0xA7,
+        ];
+        let result = frames(&stream);
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            Some(result[0].message.clone()),
+            message("8D40621D58C382D690C8AC2863A7")
+        );
+        assert_eq!(result[0].timestamp, Some(0x000102030405));
+        assert_eq!(result[0].signal, Some(0x7F));
+    }
+
+    #[test]
+    fn test_beast_escaped_bytes() {
+        let stream = [
+            0x00, 0x1A, b'2', 0x00, 0x00, 0x00, 0x00, 0x1A, 0x1A, 0x00, 0x1A, 0x1A, 0x02, 0xE1,
+            0x97, 0xB0, 0x01, 0x79, 0xC3,
+        ];
+        let result = frames(&stream);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].timestamp, Some(0x1A00));
+        assert_eq!(result[0].signal, Some(0x1A));
+        assert_eq!(Some(result[0].message.clone()), message("02E197B00179C3"));
+    }
+
+    #[test]
+    fn test_beast_skips_mode_ac_and_truncated() {
+        let stream = [
+            0x1A, b'1', 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x12, 0x34, 0x1A, b'2', 0x00,
+            0x00, 0x1A, b'2', 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x20, 0x02, 0xE1, 0x97, 0xB0,
+            0x01, 0x79, 0xC3,
+        ];
+        let result = frames(&stream);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].timestamp, Some(2));
+        assert_eq!(result[0].signal, Some(0x20));
+    }
+}
@@ -0,0 +1,228 @@
+use crate::decoder::Frame;
+use chrono::{DateTime, Duration, Utc};
+use std::thread::sleep;
+use std::time::Instant;
+
+/// Frequency of the receiver MLAT counter, ticks per second.
+const TICKS_PER_SECOND: u64 = 12_000_000;
+
+/// Time base of the decoder.
+///
+/// `frame_time` gives the time a frame was received, it is used for the plane
+/// updates and CPR pairing. `now` gives the current time, used for expiry and display.
+pub trait Clock {
+    fn frame_time(&mut self, frame: &Frame) -> DateTime<Utc>;
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Converts the receiver 12 MHz timestamps of the frames into UTC time.
+///
+/// The first timestamped frame is anchored to the given time, later frames are
+/// placed relative to it. A counter that goes backwards (receiver restart) is anchored again.
+#[derive(Debug, Default)]
+pub struct ReceiverClock {
+    anchor: Option<(u64, DateTime<Utc>)>,
+}
+
+impl ReceiverClock {
+    pub fn new() -> Self {
+        ReceiverClock { anchor: None }
+    }
+
+    /// Returns `None` for frames without a receiver timestamp.
+    pub fn time(&mut self, frame: &Frame, anchor: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        frame
+            .timestamp
+            .filter(|&ticks| ticks != 0)
+            .map(|ticks| match self.anchor {
+                Some((anchor_ticks, anchor_time)) if ticks >= anchor_ticks => {
+                    anchor_time + ticks_to_duration(ticks - anchor_ticks)
+                }
+                _ => {
+                    self.anchor = Some((ticks, anchor));
+                    anchor
+                }
+            })
+    }
+}
+
+/// Clock of the live feed, frames without a receiver timestamp get the current time.
+#[derive(Debug, Default)]
+pub struct WallClock {
+    receiver: ReceiverClock,
+}
+
+impl WallClock {
+    pub fn new() -> Self {
+        WallClock {
+            receiver: ReceiverClock::new(),
+        }
+    }
+}
+
+impl Clock for WallClock {
+    fn frame_time(&mut self, frame: &Frame) -> DateTime<Utc> {
+        let now = Utc::now();
+        self.receiver.time(frame, now).unwrap_or(now)
+    }
+
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Clock of a recording, time follows the receiver timestamps of the frames.
+///
+/// With a `speed` above zero the replay is paced, `1.0` plays the recording in real
+/// time, `10.0` ten times faster. Zero replays as fast as possible. A recording without
+/// receiver timestamps runs on the wall time, `speed` times faster when set. Frames without
+/// a timestamp in a timestamped recording keep the time of the previous frame.
+#[derive(Debug)]
+pub struct ReplayClock {
+    receiver: ReceiverClock,
+    speed: f64,
+    start: Option<(DateTime<Utc>, Instant)>,
+    wall_start: Option<(DateTime<Utc>, Instant)>,
+    timestamped: bool,
+    current: DateTime<Utc>,
+}
+
+impl ReplayClock {
+    pub fn new(speed: f64) -> Self {
+        ReplayClock {
+            receiver: ReceiverClock::new(),
+            speed,
+            start: None,
+            wall_start: None,
+            timestamped: false,
+            current: Utc::now(),
+        }
+    }
+
+    fn pace(&mut self, time: DateTime<Utc>) {
+        match self.start {
+            Some((start_time, start_instant)) if self.speed > 0.0 => {
+                if let Ok(recorded) = time.signed_duration_since(start_time).to_std() {
+                    let elapsed = start_instant.elapsed();
+                    let target = recorded.div_f64(self.speed);
+                    if target > elapsed {
+                        sleep(target - elapsed);
+                    }
+                }
+            }
+            Some(_) => {}
+            None => self.start = Some((time, Instant::now())),
+        }
+    }
+
+    /// Wall time since the first frame, scaled by the replay speed.
+    fn wall_time(&mut self) -> DateTime<Utc> {
+        let (start_time, start_instant) = *self
+            .wall_start
+            .get_or_insert_with(|| (Utc::now(), Instant::now()));
+        let elapsed = match self.speed > 0.0 {
+            true => start_instant.elapsed().mul_f64(self.speed),
+            false => start_instant.elapsed(),
+        };
+        start_time + Duration::from_std(elapsed).unwrap_or_default()
+    }
+}
+
+impl Clock for ReplayClock {
+    fn frame_time(&mut self, frame: &Frame) -> DateTime<Utc> {
+        match self.receiver.time(frame, self.current) {
+            Some(time) => {
+                self.timestamped = true;
+                self.pace(time);
+                self.current = time;
+            }
+            None if !self.timestamped => self.current = self.wall_time(),
+            None => {}
+        }
+        self.current
+    }
+
+    fn now(&self) -> DateTime<Utc> {
+        self.current
+    }
+}
+
+fn ticks_to_duration(ticks: u64) -> Duration {
+    Duration::seconds((ticks / TICKS_PER_SECOND) as i64)
+        + Duration::nanoseconds(((ticks % TICKS_PER_SECOND) * 1000 / 12) as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_receiver_clock() {
+        let mut clock = ReceiverClock::new();
+        let now = Utc::now();
+        let first = clock.time(&Frame::from_data(vec![], Some(12_000_000), None), now);
+        let second = clock.time(&Frame::from_data(vec![], Some(48_000_006), None), now);
+        assert_eq!(first, Some(now));
+        assert_eq!(
+            second.unwrap().signed_duration_since(now),
+            Duration::seconds(3) + Duration::nanoseconds(500)
+        );
+        assert_eq!(clock.time(&Frame::new(vec![]), now), None);
+    }
+
+    #[test]
+    fn test_receiver_clock_reset() {
+        let mut clock = ReceiverClock::new();
+        let now = Utc::now();
+        let first = clock.time(&Frame::from_data(vec![], Some(120_000_000), None), now);
+        let later = now + Duration::seconds(10);
+        let second = clock.time(&Frame::from_data(vec![], Some(12_000_000), None), later);
+        assert_eq!(first, Some(now));
+        assert_eq!(second, Some(later));
+    }
+
+    #[test]
+    fn test_replay_clock() {
+        let mut clock = ReplayClock::new(0.0);
+        let first = clock.frame_time(&Frame::from_data(vec![], Some(12_000_000), None));
+        sleep(std::time::Duration::from_millis(2));
+        let second = clock.frame_time(&Frame::new(vec![]));
+        let third = clock.frame_time(&Frame::from_data(vec![], Some(12_000_000 * 3600), None));
+        assert_eq!(first, second);
+        assert_eq!(third.signed_duration_since(first), Duration::seconds(3599));
+        assert_eq!(clock.now(), third);
+    }
+
+    #[test]
+    fn test_replay_clock_without_timestamps() {
+        let file = std::fs::read_to_string("rec/squitters.txt").unwrap();
+        let frames = file
+            .lines()
+            .take(1000)
+            .filter_map(|squitter| crate::decoder::frame(squitter, 1))
+            .collect::<Vec<_>>();
+        assert!(frames.iter().all(|frame| frame.timestamp.is_none()));
+
+        let mut clock = ReplayClock::new(100.0);
+        let first = clock.frame_time(&frames[0]);
+        let mut last = first;
+        for frame in &frames[1..] {
+            let time = clock.frame_time(frame);
+            assert!(time >= last);
+            last = time;
+        }
+        sleep(std::time::Duration::from_millis(20));
+        let time = clock.frame_time(&frames[0]);
+        assert!(time.signed_duration_since(first) >= Duration::seconds(2));
+        assert_eq!(clock.now(), time);
+    }
+
+    #[test]
+    fn test_replay_clock_pace() {
+        let mut clock = ReplayClock::new(10.0);
+        let start = Instant::now();
+        clock.frame_time(&Frame::from_data(vec![], Some(1), None));
+        clock.frame_time(&Frame::from_data(vec![], Some(1 + 1_200_000), None));
+        assert!(start.elapsed() >= std::time::Duration::from_millis(10));
+    }
+}
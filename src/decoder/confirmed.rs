@@ -0,0 +1,96 @@
+use crate::decoder::{ic, icao};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+/// Addresses learned from the frames with a checked parity.
+///
+/// The address of the DF0, DF4, DF5, DF16, DF20 and DF21 replies is recovered from the
+/// parity, any bit error gives a phantom address. Such an address is accepted only when
+/// the aircraft has sent a DF11, DF17 or DF18 frame within the last `ttl` seconds.
+/// A `ttl` of 0 accepts every address.
+#[derive(Debug, Default)]
+pub struct ConfirmedAddresses {
+    ttl: i64,
+    addresses: HashMap<u32, DateTime<Utc>>,
+    /// Number of the address/parity frames dropped with an unconfirmed address.
+    pub unconfirmed: u64,
+}
+
+impl ConfirmedAddresses {
+    pub fn new(ttl: i64) -> Self {
+        ConfirmedAddresses {
+            ttl,
+            addresses: HashMap::new(),
+            unconfirmed: 0,
+        }
+    }
+
+    /// Confirms the address of a DF11 with a valid interrogator code or a DF17/DF18 frame,
+    /// the extended squitters are repaired or dropped before they get here.
+    pub fn confirm(&mut self, message: &[u32], df: u32, time: DateTime<Utc>) {
+        let checked = match df {
+            11 => ic(message).is_some(),
+            17 | 18 => true,
+            _ => false,
+        };
+        if let Some(icao) = icao(message, df).filter(|_| checked) {
+            self.addresses.insert(icao, time);
+        }
+    }
+
+    /// Checks the address of the frame, the frames with an unconfirmed address recovered
+    /// from the parity are counted.
+    pub fn is_confirmed(&mut self, icao: u32, df: u32, time: DateTime<Utc>) -> bool {
+        if self.ttl == 0 || !matches!(df, 0 | 4 | 5 | 16 | 20 | 21) {
+            return true;
+        }
+        let confirmed = self.addresses.get(&icao).is_some_and(|confirmed| {
+            time.signed_duration_since(*confirmed).num_seconds() <= self.ttl
+        });
+        if !confirmed {
+            self.unconfirmed += 1;
+        }
+        confirmed
+    }
+
+    /// Forgets the addresses not confirmed within the `ttl`.
+    pub fn expire(&mut self, now: DateTime<Utc>) {
+        let ttl = self.ttl;
+        self.addresses
+            .retain(|_, confirmed| now.signed_duration_since(*confirmed).num_seconds() <= ttl);
+        self.addresses.shrink_to_fit();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::{df, message};
+    use chrono::Duration;
+
+    #[test]
+    fn test_confirmed_addresses() {
+        let time = DateTime::default();
+        let mut confirmed = ConfirmedAddresses::new(60);
+        let valid = message("28001B8AE9122D").unwrap();
+        let phantom = message("28001B8AE9122C").unwrap();
+        let df5 = df(&valid).unwrap();
+
+        assert!(!confirmed.is_confirmed(icao(&valid, df5).unwrap(), df5, time));
+
+        let ext = message("8D4CA2D458C382D690C8ACE5BD77").unwrap();
+        confirmed.confirm(&ext, 17, time);
+        assert!(confirmed.is_confirmed(icao(&valid, df5).unwrap(), df5, time));
+        assert!(!confirmed.is_confirmed(icao(&phantom, df5).unwrap(), df5, time));
+        assert!(confirmed.is_confirmed(0x4CA2D5, 17, time));
+        assert_eq!(confirmed.unconfirmed, 2);
+
+        let later = time + Duration::seconds(61);
+        assert!(!confirmed.is_confirmed(0x4CA2D4, df5, later));
+        confirmed.expire(later);
+        assert!(confirmed.addresses.is_empty());
+
+        let mut disabled = ConfirmedAddresses::new(0);
+        assert!(disabled.is_confirmed(0x4CA2D5, df5, time));
+    }
+}
@@ -0,0 +1,102 @@
+use crate::decoder::{self, range_value, ResolutionAdvisory};
+use std::fmt::{self, Display};
+
+/// DF16 long air-air surveillance reply.
+#[derive(Debug)]
+pub struct Acs {
+    pub df: Option<u32>,
+    pub icao: Option<u32>,
+    pub vertical_status: Option<u32>,
+    pub sensitivity_level: Option<u32>,
+    pub reply_information: Option<u32>,
+    pub altitude: Option<i32>,
+    pub resolution_advisory: Option<ResolutionAdvisory>,
+}
+
+impl Default for Acs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Acs {
+    pub fn new() -> Self {
+        Acs {
+            df: None,
+            icao: None,
+            vertical_status: None,
+            sensitivity_level: None,
+            reply_information: None,
+            altitude: None,
+            resolution_advisory: None,
+        }
+    }
+}
+
+impl Display for Acs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(v) = self.df {
+            write!(f, "DF{:02}", v)?
+        } else {
+            write!(f, "")?
+        }
+        if let Some(v) = self.icao {
+            write!(f, ",{:X}", v)?
+        } else {
+            write!(f, ",")?
+        }
+        if let Some(v) = self.vertical_status {
+            write!(f, ",{}", v)?
+        } else {
+            write!(f, ",")?
+        }
+        if let Some(v) = self.sensitivity_level {
+            write!(f, ",{}", v)?
+        } else {
+            write!(f, ",")?
+        }
+        if let Some(v) = self.reply_information {
+            write!(f, ",{}", v)?
+        } else {
+            write!(f, ",")?
+        }
+        if let Some(v) = self.altitude {
+            write!(f, ",{}", v)?
+        } else {
+            write!(f, ",")?
+        }
+        if let Some(v) = &self.resolution_advisory {
+            writeln!(
+                f,
+                ",{:014b},{:04b},{},{}",
+                v.ara, v.rac, v.terminated as u32, v.multiple_threats as u32
+            )
+        } else {
+            writeln!(f, ",,,,")
+        }
+    }
+}
+
+impl decoder::Downlink for Acs {
+    fn from_message(message: &[u32]) -> Result<Self, &str> {
+        let mut dl = Acs::new();
+        dl.update(message);
+        Ok(dl)
+    }
+
+    fn update(&mut self, message: &[u32]) {
+        if let Some(df) = decoder::df(message) {
+            self.df = Some(df);
+            self.icao = decoder::icao(message, df);
+            self.vertical_status = range_value(message, 6, 6);
+            self.sensitivity_level = range_value(message, 9, 11);
+            self.reply_information = range_value(message, 14, 17);
+            self.altitude = decoder::altitude(message, df);
+            self.resolution_advisory = ResolutionAdvisory::from_message(message);
+        }
+    }
+
+    fn icao(&self) -> Option<u32> {
+        self.icao
+    }
+}
@@ -0,0 +1,197 @@
+use crate::decoder::{
+    self, Capability, DataLinkCapability, Hazard, HeadingAndSpeed, Meteo, Registration,
+    ResolutionAdvisory, SelectedVerticalIntention, TrackAndTurn,
+};
+use std::fmt::{self, Display};
+
+/// Comm-B register (MB field) of the DF20 and DF21 replies.
+///
+/// Only the BDS 1,0, 2,0 and 3,0 registers identify themselves. Any other contents is
+/// decoded as each register it validates as, the plane picks the candidate its
+/// capability report (BDS 1,7) allows.
+#[derive(Debug, Default)]
+pub struct CommB {
+    pub bds: (u32, u32),
+    pub data_link_capability: Option<DataLinkCapability>,
+    pub ais: Option<String>,
+    pub capability: Option<Capability>,
+    pub registration: Option<Registration>,
+    pub threat_encounter: Option<char>,
+    pub resolution_advisory: Option<ResolutionAdvisory>,
+    pub selected_vertical_intention: Option<SelectedVerticalIntention>,
+    pub meteo: Option<Meteo>,
+    pub hazard: Option<Hazard>,
+    pub track_and_turn: Option<TrackAndTurn>,
+    pub heading_and_speed: Option<HeadingAndSpeed>,
+}
+
+impl CommB {
+    pub fn from_message(message: &[u32], df: u32) -> Self {
+        let bds = decoder::bds(message);
+        let mut comm_b = CommB {
+            bds,
+            ..CommB::default()
+        };
+        match bds {
+            (1, 0) => {
+                comm_b.data_link_capability = decoder::is_bds_1_0(message);
+            }
+            (2, 0) => {
+                comm_b.ais = decoder::ais(message);
+            }
+            (3, 0) => {
+                comm_b.threat_encounter = decoder::threat_encounter(message);
+                comm_b.resolution_advisory = decoder::resolution_advisory(message, df);
+            }
+            (0, 0) => {
+                comm_b.capability = decoder::is_bds_1_7(message);
+                if comm_b.capability.is_none() {
+                    comm_b.registration = decoder::is_bds_2_1(message);
+                    comm_b.selected_vertical_intention = decoder::is_bds_4_0(message);
+                    comm_b.meteo = decoder::is_bds_4_4(message);
+                    comm_b.hazard = decoder::is_bds_4_5(message);
+                    comm_b.track_and_turn = decoder::is_bds_5_0(message);
+                    comm_b.heading_and_speed = decoder::is_bds_6_0(message);
+                }
+            }
+            _ => {}
+        }
+        comm_b
+    }
+}
+
+fn write_value<T: Display>(f: &mut fmt::Formatter, value: Option<T>) -> fmt::Result {
+    match value {
+        Some(v) => write!(f, ",{}", v),
+        None => write!(f, ","),
+    }
+}
+
+/// The BDS followed by the transponder level and subnetwork version, the AIS, capability, the BDS 2,1
+/// candidate, threat and RA, the BDS 4,0, 4,4, 4,5, 5,0 and 6,0 candidates, empty columns for
+/// the registers the contents is not valid for.
+impl Display for CommB {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, ",{}.{}", self.bds.0, self.bds.1)?;
+        write_value(f, self.data_link_capability.map(|v| v.transponder_level()))?;
+        write_value(f, self.data_link_capability.map(|v| v.subnetwork_version))?;
+        write_value(f, self.ais.as_deref())?;
+        write_value(f, self.capability.map(|v| format!("{:06X}", v.flags)))?;
+        match &self.registration {
+            Some(v) => {
+                write_value(f, v.aircraft.as_deref())?;
+                write_value(f, v.airline.as_deref())?;
+            }
+            None => write!(f, ",,")?,
+        }
+        write_value(f, self.threat_encounter)?;
+        write_value(f, self.resolution_advisory)?;
+        match &self.selected_vertical_intention {
+            Some(v) => {
+                write_value(f, v.mcp_selected_altitude)?;
+                write_value(f, v.fms_selected_altitude)?;
+                write_value(f, v.barometric_pressure_setting)?;
+                write_value(f, v.target_altitude_source)?;
+                write_value(
+                    f,
+                    v.mcp_modes.map(|modes| {
+                        [
+                            (modes.vnav, 'V'),
+                            (modes.altitude_hold, 'H'),
+                            (modes.approach, 'P'),
+                        ]
+                        .iter()
+                        .map(|&(engaged, letter)| if engaged { letter } else { '-' })
+                        .collect::<String>()
+                    }),
+                )?;
+            }
+            None => write!(f, ",,,,,")?,
+        }
+        match &self.meteo {
+            Some(v) => {
+                write_value(f, v.source)?;
+                write_value(f, v.temp)?;
+                write_value(f, v.wind.map(|wind| wind.0))?;
+                write_value(f, v.wind.map(|wind| wind.1))?;
+                write_value(f, v.humidity)?;
+                write_value(f, v.turbulence)?;
+                write_value(f, v.pressure)?;
+            }
+            None => write!(f, ",,,,,,,")?,
+        }
+        match &self.hazard {
+            Some(v) => write!(f, ",{}", v)?,
+            None => write!(f, ",,,,,,,,")?,
+        }
+        match &self.track_and_turn {
+            Some(v) => {
+                write_value(f, v.roll_angle)?;
+                write_value(f, v.track_angle)?;
+                write_value(f, v.track_angle_rate)?;
+                write_value(f, v.ground_speed)?;
+                write_value(f, v.true_airspeed)?;
+            }
+            None => write!(f, ",,,,,")?,
+        }
+        match &self.heading_and_speed {
+            Some(v) => {
+                write_value(f, v.magnetic_heading)?;
+                write_value(f, v.indicated_airspeed)?;
+                write_value(f, v.mach_number)?;
+                write_value(f, v.barometric_altitude_rate)?;
+                write_value(f, v.internal_vertical_velocity)?;
+            }
+            None => write!(f, ",,,,,")?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::message;
+
+    #[test]
+    fn test_comm_b() {
+        if let Some(message) = message("A800161110010080E6000073D501") {
+            let comm_b = CommB::from_message(&message, 21);
+            assert_eq!(comm_b.bds, (1, 0));
+            assert!(comm_b.to_string().starts_with(",1.0,2,0,,,,,"));
+        }
+        if let Some(message) = message("A0001838300000000000007ADA59") {
+            let comm_b = CommB::from_message(&message, 20);
+            assert_eq!(comm_b.bds, (3, 0));
+            assert!(comm_b.selected_vertical_intention.is_none());
+        }
+        if let Some(message) = message("A000029C85E42F313000007047D3") {
+            let comm_b = CommB::from_message(&message, 20);
+            assert_eq!(comm_b.bds, (0, 0));
+            let intention = comm_b.selected_vertical_intention.as_ref().unwrap();
+            assert_eq!(intention.mcp_selected_altitude, Some(3008));
+            assert_eq!(intention.barometric_pressure_setting, Some(1020));
+            assert!(comm_b.heading_and_speed.is_none());
+            assert!(comm_b
+                .to_string()
+                .starts_with(",0.0,,,,,,,,,3008,3008,1020,"));
+        }
+        if let Some(message) = message("A00004128F39F91A7E27C46ADC21") {
+            let comm_b = CommB::from_message(&message, 20);
+            let heading_and_speed = comm_b.heading_and_speed.unwrap();
+            assert_eq!(heading_and_speed.magnetic_heading, Some(42));
+            assert_eq!(heading_and_speed.indicated_airspeed, Some(252));
+            assert!(comm_b.selected_vertical_intention.is_none());
+        }
+        if let Some(message) = message("A00018388A4882830411491DB2D6") {
+            let comm_b = CommB::from_message(&message, 20);
+            assert!(comm_b.to_string().starts_with(",0.0,,,,,EIDEA,EI,"));
+        }
+        if let Some(message) = message("A000183828B701CE0002803DA3E1") {
+            let comm_b = CommB::from_message(&message, 20);
+            assert!(comm_b
+                .to_string()
+                .starts_with(",0.0,,,,,,,,,,,,,,GNSS,-50,45,270,,1,,"));
+        }
+    }
+}
@@ -1,10 +1,12 @@
 use std::fmt::{self, Debug, Display};
 
 use super::*;
+use crate::decoder::AddressType;
 
 #[derive(Debug)]
 pub enum DF {
     SRT(Srt),
+    ACS(Acs),
     EXT(Ext),
     MDS(Mds),
 }
@@ -30,6 +32,7 @@ impl Display for DF {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             DF::SRT(v) => write!(f, "{}", v),
+            DF::ACS(v) => write!(f, "{}", v),
             DF::EXT(v) => write!(f, "{}", v),
             DF::MDS(v) => write!(f, "{}", v),
         }
@@ -41,8 +44,9 @@ impl Downlink for DF {
         match df(message) {
             Some(value) => {
                 let dl = match value {
-                    0..=16 => DF::SRT(Srt::from_message(message)?),
-                    17 => DF::EXT(Ext::from_message(message)?),
+                    0..=15 => DF::SRT(Srt::from_message(message)?),
+                    16 => DF::ACS(Acs::from_message(message)?),
+                    17 | 18 => DF::EXT(Ext::from_message(message)?),
                     20 | 21 => DF::MDS(Mds::from_message(message)?),
                     _ => DF::SRT(Srt::new()),
                 };
@@ -55,6 +59,7 @@ impl Downlink for DF {
     fn update(&mut self, message: &[u32]) {
         match self {
             DF::SRT(v) => v.update(message),
+            DF::ACS(v) => v.update(message),
             DF::EXT(v) => v.update(message),
             DF::MDS(v) => v.update(message),
         }
@@ -63,12 +68,22 @@ impl Downlink for DF {
     fn icao(&self) -> Option<u32> {
         match self {
             DF::SRT(v) => v.icao,
+            DF::ACS(v) => v.icao,
             DF::EXT(v) => v.icao,
             DF::MDS(v) => v.icao,
         }
     }
 }
 
+impl DF {
+    pub fn address_type(&self) -> AddressType {
+        match self {
+            DF::EXT(v) => v.address_type,
+            _ => AddressType::Icao,
+        }
+    }
+}
+
 pub trait Downlink: Sized {
     fn from_message(message: &[u32]) -> Result<Self, &str>;
     fn update(&mut self, message: &[u32]);
@@ -14,6 +14,12 @@ impl Display for Ext {
             write!(f, ",")?
         }
         write!(f, ",{}", self.capability)?;
+        if let Some(v) = self.control_field {
+            write!(f, ",{}", v)?
+        } else {
+            write!(f, ",")?
+        }
+        write!(f, ",{:?}", self.address_type)?;
         write!(f, ",{}.{}", self.message_type.0, self.message_type.1)?;
         if let Some(v) = &self.ais {
             write!(f, ",{}", v)?
@@ -91,7 +97,27 @@ impl Display for Ext {
             write!(f, ",")?
         }
         if let Some(v) = self.adsb_version {
-            writeln!(f, ",{:X}", v)
+            write!(f, ",{:X}", v)?
+        } else {
+            write!(f, ",")?
+        }
+        if let Some(v) = &self.emergency {
+            write!(f, ",{}", v)?
+        } else {
+            write!(f, ",")?
+        }
+        if let Some(v) = self.squawk {
+            write!(f, ",{:04}", v)?
+        } else {
+            write!(f, ",")?
+        }
+        if let Some(v) = &self.resolution_advisory {
+            write!(f, ",{}", v)?
+        } else {
+            write!(f, ",")?
+        }
+        if let Some(v) = self.target_state.as_ref().and_then(|v| v.selected_altitude) {
+            writeln!(f, ",{}", v)
         } else {
             writeln!(f, ",")
         }
@@ -1,11 +1,15 @@
 mod display;
 mod update;
 
+use crate::decoder::{AddressType, Emergency, OperationalStatus, ResolutionAdvisory, TargetState};
+
 #[derive(Debug)]
 pub struct Ext {
     pub df: Option<u32>,
     pub icao: Option<u32>,
     pub capability: u32,
+    pub control_field: Option<u32>,
+    pub address_type: AddressType,
     pub message_type: (u32, u32),
     pub ais: Option<String>,
     pub category: Option<(u32, u32)>,
@@ -16,14 +20,21 @@ pub struct Ext {
     pub track_source: Option<char>,
     pub heading: Option<u32>,
     pub heading_source: Option<char>,
-    pub altitude: Option<u32>,
+    pub altitude: Option<i32>,
     pub altitude_source: Option<char>,
     pub altitude_delta: Option<i32>,
-    pub altitude_gnss: Option<u32>,
+    pub altitude_gnss: Option<i32>,
     pub vrate: Option<i32>,
     pub vrate_source: Option<char>,
     pub surveillance_status: Option<char>,
+    pub nic_supplement_b: Option<u32>,
+    pub nacv: Option<u32>,
     pub adsb_version: Option<u32>,
+    pub emergency: Option<Emergency>,
+    pub squawk: Option<u32>,
+    pub resolution_advisory: Option<ResolutionAdvisory>,
+    pub target_state: Option<Box<TargetState>>,
+    pub operational_status: Option<Box<OperationalStatus>>,
 }
 
 impl Default for Ext {
@@ -38,6 +49,8 @@ impl Ext {
             df: None,
             icao: None,
             capability: 0,
+            control_field: None,
+            address_type: AddressType::Icao,
             message_type: (0, 0),
             ais: None,
             category: None,
@@ -55,7 +68,14 @@ impl Ext {
             vrate: None,
             vrate_source: None,
             surveillance_status: None,
+            nic_supplement_b: None,
+            nacv: None,
             adsb_version: None,
+            emergency: None,
+            squawk: None,
+            resolution_advisory: None,
+            target_state: None,
+            operational_status: None,
         }
     }
 }
@@ -19,6 +19,7 @@ impl Ext {
             9..=18 => {
                 self.altitude = decoder::altitude(message, df);
                 self.surveillance_status = Some(decoder::surveillance_status(message));
+                self.nic_supplement_b = decoder::nic_supplement_b(message);
             }
             _ => {}
         }
@@ -27,6 +28,7 @@ impl Ext {
     fn update_mt_19(&mut self, message: &[u32]) {
         self.vrate = decoder::vertical_rate(message);
         self.altitude_delta = decoder::altitude_delta(message);
+        self.nacv = decoder::nacv(message);
         match self.message_type.1 {
             1 => {
                 (self.track, self.grspeed) = decoder::track_and_groundspeed(message, false);
@@ -47,10 +49,63 @@ impl Ext {
     fn update_mt_20_22(&mut self, message: &[u32]) {
         self.altitude_gnss = decoder::altitude_gnss(message);
         self.surveillance_status = Some(decoder::surveillance_status(message));
+        self.nic_supplement_b = decoder::nic_supplement_b(message);
+    }
+
+    fn update_mt_28(&mut self, message: &[u32], df: u32) {
+        match self.message_type.1 {
+            1 => {
+                self.emergency = decoder::emergency(message);
+                self.squawk = decoder::emergency_squawk(message);
+            }
+            2 => {
+                self.resolution_advisory = decoder::resolution_advisory(message, df);
+            }
+            _ => {}
+        }
+    }
+
+    fn update_mt_29(&mut self, message: &[u32]) {
+        self.target_state = decoder::target_state(message).map(Box::new);
     }
 
     fn update_mt_31(&mut self, message: &[u32]) {
         self.adsb_version = decoder::version(message);
+        self.operational_status = decoder::operational_status(message).map(Box::new);
+    }
+
+    fn update_coarse_tisb(&mut self, message: &[u32]) {
+        self.cpr = decoder::coarse_cpr(message);
+        self.altitude = decoder::altitude_coarse(message);
+        (self.track, self.grspeed) = decoder::coarse_track_and_groundspeed(message);
+    }
+
+    fn update_message_type(&mut self, message: &[u32], df: u32) {
+        self.message_type = decoder::message_type(message);
+        match self.message_type.0 {
+            1..=4 => {
+                self.update_mt_1_4(message);
+            }
+            5..=18 => {
+                self.update_mt_5_18(message, df);
+            }
+            19 => {
+                self.update_mt_19(message);
+            }
+            20..=22 => {
+                self.update_mt_20_22(message);
+            }
+            28 => {
+                self.update_mt_28(message, df);
+            }
+            29 => {
+                self.update_mt_29(message);
+            }
+            31 => {
+                self.update_mt_31(message);
+            }
+            _ => {}
+        }
     }
 }
 
@@ -65,25 +120,22 @@ impl decoder::Downlink for Ext {
         if let Some(df) = decoder::df(message) {
             self.df = Some(df);
             self.icao = decoder::icao(message, df);
-            self.capability = decoder::ca(message);
-            self.message_type = decoder::message_type(message);
-            match self.message_type.0 {
-                1..=4 => {
-                    self.update_mt_1_4(message);
-                }
-                5..=18 => {
-                    self.update_mt_5_18(message, df);
-                }
-                19 => {
-                    self.update_mt_19(message);
-                }
-                20..=22 => {
-                    self.update_mt_20_22(message);
+            self.address_type = decoder::address_type(message, df).unwrap_or_default();
+            match df {
+                18 => {
+                    let cf = decoder::cf(message);
+                    self.control_field = Some(cf);
+                    match cf {
+                        // CF3 coarse TIS-B has no type code, CF4 and CF7 carry no target data
+                        3 => self.update_coarse_tisb(message),
+                        4 | 7 => {}
+                        _ => self.update_message_type(message, df),
+                    }
                 }
-                31 => {
-                    self.update_mt_31(message);
+                _ => {
+                    self.capability = decoder::ca(message);
+                    self.update_message_type(message, df);
                 }
-                _ => {}
             }
         };
     }
@@ -1,8 +1,12 @@
+mod air_air;
+mod comm_b;
 mod dfs;
 mod extended;
 mod mode_s;
 mod short;
 
+pub(crate) use air_air::*;
+pub(crate) use comm_b::*;
 pub use dfs::*;
 pub(crate) use extended::*;
 pub(crate) use mode_s::*;
@@ -48,6 +52,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_df_16_resolution_advisory() {
+        let squitter = "80E19718308C00052C6B4480A626";
+        if let Some(message) = message(squitter) {
+            match DF::from_message(&message) {
+                Ok(DF::ACS(acs)) => {
+                    assert_eq!(acs.icao, Some(0x4CA13D));
+                    assert_eq!(acs.vertical_status, Some(0));
+                    assert_eq!(acs.sensitivity_level, Some(7));
+                    assert_eq!(acs.reply_information, Some(3));
+                    assert!(acs.resolution_advisory.is_some_and(|ra| ra.is_active()));
+                }
+                _ => panic!("DF16 is not a long air-air surveillance reply"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_df_16_resolution_advisory_ara() {
+        let squitter = "80E1971830E200052C6B44FBE187";
+        if let Some(message) = message(squitter) {
+            match DF::from_message(&message) {
+                Ok(DF::ACS(acs)) => {
+                    assert_eq!(acs.icao, Some(0x4CA13D));
+                    assert_eq!(acs.vertical_status, Some(0));
+                    assert_eq!(acs.sensitivity_level, Some(7));
+                    assert_eq!(acs.reply_information, Some(3));
+                    assert!(acs.resolution_advisory.is_some_and(|ra| ra.is_active()));
+                }
+                _ => panic!("DF16 is not a long air-air surveillance reply"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_df_18_coarse_tisb() {
+        let squitter = "9340621D03871438B5A645743DAA";
+        if let Some(message) = message(squitter) {
+            match DF::from_message(&message) {
+                Ok(DF::EXT(ext)) => {
+                    assert_eq!(ext.control_field, Some(3));
+                    assert_eq!(ext.address_type, crate::decoder::AddressType::TisbIcao);
+                    assert_eq!(ext.icao, Some(0x40621D));
+                    assert_eq!(ext.altitude, Some(38000));
+                    assert_eq!(ext.cpr, Some((0, 92992, 51360)));
+                }
+                _ => panic!("DF18 is not an extended squitter"),
+            }
+        }
+    }
+
     #[test]
     fn test_df_21() {
         let squitter = "A8281200200464B3CF7820CD194C";
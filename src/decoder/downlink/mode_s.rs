@@ -1,11 +1,19 @@
-use crate::decoder;
+use super::short::write_flight_status;
+use super::CommB;
+use crate::decoder::{self, FlightStatus, Interrogator};
 use std::fmt::{self, Display};
 
 #[derive(Debug)]
 pub struct Mds {
     pub df: Option<u32>,
     pub icao: Option<u32>,
-    pub altitude: Option<u32>,
+    pub altitude: Option<i32>,
+    pub squawk: Option<u32>,
+    pub flight_status: Option<FlightStatus>,
+    pub downlink_request: Option<u32>,
+    pub utility_message: Option<(u32, u32)>,
+    pub interrogator: Option<Interrogator>,
+    pub comm_b: Option<Box<CommB>>,
 }
 
 impl Default for Mds {
@@ -20,6 +28,12 @@ impl Mds {
             df: None,
             icao: None,
             altitude: None,
+            squawk: None,
+            flight_status: None,
+            downlink_request: None,
+            utility_message: None,
+            interrogator: None,
+            comm_b: None,
         }
     }
 }
@@ -37,10 +51,30 @@ impl Display for Mds {
             write!(f, ",")?
         }
         if let Some(v) = self.altitude {
-            writeln!(f, ",{}", v)
+            write!(f, ",{}", v)?
         } else {
-            writeln!(f, ",")
+            write!(f, ",")?
+        }
+        if let Some(v) = self.squawk {
+            write!(f, ",{}", v)?
+        } else {
+            write!(f, ",")?
+        }
+        if let Some(v) = self.interrogator {
+            write!(f, ",{}", v)?
+        } else {
+            write!(f, ",")?
+        }
+        write_flight_status(
+            f,
+            self.flight_status,
+            self.downlink_request,
+            self.utility_message,
+        )?;
+        if let Some(v) = &self.comm_b {
+            write!(f, "{}", v)?
         }
+        writeln!(f)
     }
 }
 
@@ -55,7 +89,16 @@ impl decoder::Downlink for Mds {
         if let Some(df) = decoder::df(message) {
             self.df = Some(df);
             self.icao = decoder::icao(message, df);
-            self.altitude = decoder::altitude(message, df);
+            match df {
+                20 => self.altitude = decoder::altitude(message, df),
+                21 => self.squawk = decoder::squawk(message),
+                _ => {}
+            }
+            self.flight_status = decoder::flight_status(message);
+            self.downlink_request = decoder::downlink_request(message);
+            self.utility_message = decoder::utility_message(message);
+            self.interrogator = decoder::interrogator(message, df);
+            self.comm_b = Some(Box::new(CommB::from_message(message, df)));
         }
     }
 
@@ -1,4 +1,4 @@
-use crate::decoder;
+use crate::decoder::{self, FlightStatus, Interrogator};
 use std::fmt::{self, Display};
 
 #[derive(Debug)]
@@ -7,7 +7,11 @@ pub struct Srt {
     pub icao: Option<u32>,
     pub squawk: Option<u32>,
     pub capability: Option<u32>,
-    pub altitude: Option<u32>,
+    pub altitude: Option<i32>,
+    pub flight_status: Option<FlightStatus>,
+    pub downlink_request: Option<u32>,
+    pub utility_message: Option<(u32, u32)>,
+    pub interrogator: Option<Interrogator>,
 }
 
 impl Default for Srt {
@@ -24,6 +28,10 @@ impl Srt {
             squawk: None,
             capability: None,
             altitude: None,
+            flight_status: None,
+            downlink_request: None,
+            utility_message: None,
+            interrogator: None,
         }
     }
 }
@@ -41,7 +49,7 @@ impl Display for Srt {
             write!(f, ",")?
         }
         if let Some(v) = self.squawk {
-            writeln!(f, ",{}", v)?
+            write!(f, ",{}", v)?
         } else {
             write!(f, ",")?
         }
@@ -51,10 +59,51 @@ impl Display for Srt {
             write!(f, ",")?
         }
         if let Some(v) = self.altitude {
-            writeln!(f, ",{}", v)
+            write!(f, ",{}", v)?
+        } else {
+            write!(f, ",")?
+        }
+        if let Some(v) = self.interrogator {
+            write!(f, ",{}", v)?
+        } else {
+            write!(f, ",")?
+        }
+        write_flight_status(
+            f,
+            self.flight_status,
+            self.downlink_request,
+            self.utility_message,
+        )?;
+        writeln!(f)
+    }
+}
+
+/// Writes the alert, SPI and on-ground flags followed by the DR, IIS and IDS fields.
+pub(super) fn write_flight_status(
+    f: &mut fmt::Formatter,
+    flight_status: Option<FlightStatus>,
+    downlink_request: Option<u32>,
+    utility_message: Option<(u32, u32)>,
+) -> fmt::Result {
+    if let Some(v) = flight_status {
+        write!(f, ",{},{}", v.alert as u32, v.spi as u32)?;
+        if let Some(on_ground) = v.on_ground {
+            write!(f, ",{}", on_ground as u32)?
         } else {
-            writeln!(f, ",")
+            write!(f, ",")?
         }
+    } else {
+        write!(f, ",,,")?
+    }
+    if let Some(v) = downlink_request {
+        write!(f, ",{}", v)?
+    } else {
+        write!(f, ",")?
+    }
+    if let Some(v) = utility_message {
+        write!(f, ",{},{}", v.0, v.1)
+    } else {
+        write!(f, ",,")
     }
 }
 
@@ -69,6 +118,12 @@ impl decoder::Downlink for Srt {
         if let Some(df) = decoder::df(message) {
             self.df = Some(df);
             self.icao = decoder::icao(message, df);
+            self.interrogator = decoder::interrogator(message, df);
+            if df == 4 || df == 5 {
+                self.flight_status = decoder::flight_status(message);
+                self.downlink_request = decoder::downlink_request(message);
+                self.utility_message = decoder::utility_message(message);
+            }
             match df {
                 4 => {
                     self.altitude = decoder::altitude(message, df);
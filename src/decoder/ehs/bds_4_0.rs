@@ -1,6 +1,6 @@
 use log::debug;
 
-use crate::decoder::{flag_and_range_value, hex_message};
+use crate::decoder::{flag_and_range_value, hex_message, range_value, McpModes};
 
 pub(crate) fn mcp_selected_altitude(message: &[u32]) -> Option<u32> {
     debug!("MCP, {}", hex_message(message));
@@ -33,6 +33,28 @@ pub(crate) fn target_altitude_source(message: &[u32]) -> Option<u32> {
         .map(|v| v.1)
 }
 
+/// MCP/FCU mode bits, `None` when the mode status is not set.
+pub(crate) fn mcp_modes(message: &[u32]) -> Option<McpModes> {
+    flag_and_range_value(message, 80, 81, 83)
+        .filter(|&f| f.0 == 1)
+        .map(|(_, modes)| McpModes {
+            vnav: modes & 0b100 != 0,
+            altitude_hold: modes & 0b010 != 0,
+            approach: modes & 0b001 != 0,
+        })
+}
+
+/// The mode bits and the target altitude source bits must be zero without their status.
+pub(crate) fn unused_bits_clear(message: &[u32]) -> bool {
+    matches!(
+        flag_and_range_value(message, 80, 81, 83),
+        Some((1, _)) | Some((0, 0))
+    ) && matches!(
+        flag_and_range_value(message, 86, 87, 88),
+        Some((1, _)) | Some((0, 0))
+    ) && range_value(message, 84, 85) == Some(0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -51,6 +73,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_mcp_modes() {
+        if let Some(message) = message("A80004BAC4662330A801462D3BF2") {
+            assert_eq!(
+                mcp_modes(&message),
+                Some(McpModes {
+                    vnav: false,
+                    altitude_hold: true,
+                    approach: false,
+                })
+            );
+            assert_eq!(target_altitude_source(&message), Some(2));
+            assert!(unused_bits_clear(&message));
+        }
+        if let Some(message) = message("A80004BAC4662330A80046233672") {
+            assert_eq!(mcp_modes(&message), None);
+            assert!(!unused_bits_clear(&message));
+        }
+    }
+
     #[test]
     fn test_fsm_selected_altitude_value() {
         let s = [
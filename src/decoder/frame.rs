@@ -0,0 +1,41 @@
+use crate::decoder::hex_message;
+use std::fmt::{self, Display};
+
+/// A single Mode S frame together with the reception data supplied by the receiver.
+///
+/// `timestamp` is the receiver 48-bit MLAT counter (12 MHz ticks) and `signal`
+/// is the raw RSSI byte, both available only when the source provides them.
+/// `corrected_bits` is the number of bit errors repaired in the message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    pub message: Vec<u32>,
+    pub timestamp: Option<u64>,
+    pub signal: Option<u8>,
+    pub corrected_bits: u32,
+}
+
+impl Frame {
+    pub fn new(message: Vec<u32>) -> Self {
+        Frame {
+            message,
+            timestamp: None,
+            signal: None,
+            corrected_bits: 0,
+        }
+    }
+
+    pub fn from_data(message: Vec<u32>, timestamp: Option<u64>, signal: Option<u8>) -> Self {
+        Frame {
+            message,
+            timestamp,
+            signal,
+            corrected_bits: 0,
+        }
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", hex_message(&self.message))
+    }
+}
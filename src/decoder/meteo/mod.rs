@@ -1,17 +1,24 @@
-use crate::decoder::{flag_and_range_value, status_flag_and_range_value};
+use crate::decoder::{flag_and_range_value, range_value};
 
 pub(crate) fn temperature_4_4(message: &[u32]) -> Option<f64> {
     flag_and_range_value(message, 56, 57, 66).map(|(sign, value)| temp_4_4(sign, value))
 }
 
+/// Static air temperature of BDS 4,4, the sign and ten bits in two's complement with
+/// 0.25 Celsius LSB.
 fn temp_4_4(sign: u32, value: u32) -> f64 {
     let temp = value as i32;
     match sign {
         0 => temp as f64 * 0.25,
-        _ => (!temp + 1) as f64 * 0.25,
+        _ => (temp - 0x400) as f64 * 0.25,
     }
 }
 
+/// Figure of merit of the report, the source of the wind and temperature data.
+pub(crate) fn fom_4_4(message: &[u32]) -> Option<u32> {
+    range_value(message, 33, 36)
+}
+
 fn wind_speed(message: &[u32]) -> Option<u32> {
     flag_and_range_value(message, 37, 38, 46)
         .filter(|&(status, _)| status == 1)
@@ -25,11 +32,7 @@ fn wind_direction(message: &[u32]) -> Option<u32> {
 }
 
 pub(crate) fn wind_4_4(message: &[u32]) -> Option<(u32, u32)> {
-    wind_speed(message).map(|wind_speed| {
-        wind_direction(message)
-            .map(|wind_direction| (wind_speed, wind_direction))
-            .unwrap()
-    })
+    wind_speed(message).zip(wind_direction(message))
 }
 
 pub(crate) fn turbulence_4_4(message: &[u32]) -> Option<u32> {
@@ -50,22 +53,17 @@ pub(crate) fn pressure_4_4(message: &[u32]) -> Option<u32> {
         .map(|(_, value)| value)
 }
 
-pub(crate) fn temperature_4_5(message: &[u32]) -> Option<f64> {
-    status_flag_and_range_value(message, 48, 49, 50, 58)
-        .filter(|&(status, _, _)| status == 1)
-        .map(|(_, sign, value)| temp_4_5(sign, value))
-}
-
-fn temp_4_5(sign: u32, value: u32) -> f64 {
-    match sign {
-        1 => -(value as f64 * 0.25),
-        _ => value as f64 * 0.25,
+/// Static air temperature of BDS 4,5, ten bits in two's complement with 0.25 Celsius LSB.
+pub(crate) fn temp_4_5(value: u32) -> f64 {
+    match value & 0x200 {
+        0 => value as f64 * 0.25,
+        _ => (value as i32 - 0x400) as f64 * 0.25,
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::temp_4_4;
+    use super::{temp_4_4, temp_4_5};
 
     #[test]
     fn test_power() {
@@ -77,6 +75,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::erasing_op)]
     fn test_humidity_range() {
         assert_eq!((0b111111 * 100) >> 6, 98);
         assert_eq!(100 >> 6, 1);
@@ -85,7 +84,15 @@ mod tests {
 
     #[test]
     fn test_temp_4_4() {
-        assert_eq!(temp_4_4(1, 0b1111111111), -255.75);
+        assert_eq!(temp_4_4(1, 0b1111111111), -0.25);
+        assert_eq!(temp_4_4(1, 824), -50.0);
         assert_eq!(temp_4_4(0, 0b1111111111), 255.75);
     }
+
+    #[test]
+    fn test_temp_4_5() {
+        assert_eq!(temp_4_5(50), 12.5);
+        assert_eq!(temp_4_5(824), -50.0);
+        assert_eq!(temp_4_5(0x3FF), -0.25);
+    }
 }
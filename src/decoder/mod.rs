@@ -1,15 +1,23 @@
 mod adsb;
 mod bds;
+mod beast;
+mod clock;
+mod confirmed;
 mod country;
 mod downlink;
 mod ehs;
+mod frame;
 mod meteo;
 mod observer;
 mod plane;
 mod utils;
 
 pub use adsb::*;
+pub use beast::*;
+pub use clock::*;
+pub use confirmed::*;
 pub use downlink::*;
+pub use frame::*;
 pub use observer::*;
 pub use plane::*;
 pub use utils::*;
@@ -47,3 +47,20 @@ pub(crate) fn get_observer_coords() -> Option<(f64, f64)> {
     let coords = OBSERVER_COORDS.lock().unwrap();
     *coords
 }
+
+// Define the global variable for the maximum receiver range in kilometers
+lazy_static! {
+    static ref MAX_RANGE: Mutex<Option<f64>> = Mutex::new(None);
+}
+
+// Function to set the maximum receiver range, positions beyond it are rejected
+pub fn set_max_range(r: Option<f64>) {
+    let mut range = MAX_RANGE.lock().unwrap();
+    *range = r;
+}
+
+// Function to get the maximum receiver range
+pub(crate) fn get_max_range() -> Option<f64> {
+    let range = MAX_RANGE.lock().unwrap();
+    *range
+}
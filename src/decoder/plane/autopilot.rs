@@ -0,0 +1,89 @@
+use super::Plane;
+
+/// Distance beyond the selected altitude counted as a level bust, in feet.
+const LEVEL_BUST_MARGIN: i32 = 300;
+
+/// Vertical rate away from the selected altitude counted as a level bust, in feet per minute.
+const LEVEL_BUST_RATE: i32 = 100;
+
+impl Plane {
+    /// The plane has passed the MCP/FCU selected altitude (or the TC 29 selected altitude
+    /// without BDS 4,0) by more than `LEVEL_BUST_MARGIN` and keeps moving away from it.
+    pub fn is_level_bust(&self) -> bool {
+        match (
+            self.mcp_selected_altitude.or(self.selected_altitude),
+            self.altitude,
+            self.vrate,
+        ) {
+            (Some(selected), Some(altitude), Some(vrate)) if selected > 0 => {
+                let divergence = altitude - selected as i32;
+                (divergence > LEVEL_BUST_MARGIN && vrate > LEVEL_BUST_RATE)
+                    || (divergence < -LEVEL_BUST_MARGIN && vrate < -LEVEL_BUST_RATE)
+            }
+            _ => false,
+        }
+    }
+
+    /// Autopilot, VNAV, altitude hold, approach and LNAV, a letter for an engaged mode,
+    /// `-` for a disengaged one and a space when unknown.
+    ///
+    /// The BDS 4,0 mode bits take precedence over the TC 29 ones for VNAV, altitude hold
+    /// and approach, the autopilot and LNAV come from TC 29 only.
+    pub fn autopilot_state(&self) -> String {
+        let mode = |engaged: Option<bool>, letter: char| match engaged {
+            Some(true) => letter,
+            Some(false) => '-',
+            None => ' ',
+        };
+        let tc29 = self.autopilot_modes;
+        let mcp = self.mcp_modes;
+        [
+            mode(tc29.map(|v| v.autopilot), 'A'),
+            mode(mcp.map(|v| v.vnav).or(tc29.map(|v| v.vnav)), 'V'),
+            mode(
+                mcp.map(|v| v.altitude_hold)
+                    .or(tc29.map(|v| v.altitude_hold)),
+                'H',
+            ),
+            mode(mcp.map(|v| v.approach).or(tc29.map(|v| v.approach)), 'P'),
+            mode(tc29.map(|v| v.lnav), 'L'),
+        ]
+        .iter()
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::McpModes;
+
+    #[test]
+    fn test_is_level_bust() {
+        let mut plane = Plane::new();
+        plane.mcp_selected_altitude = Some(35008);
+        plane.altitude = Some(35000);
+        plane.vrate = Some(64);
+        assert!(!plane.is_level_bust());
+        plane.altitude = Some(35500);
+        assert!(!plane.is_level_bust());
+        plane.vrate = Some(1024);
+        assert!(plane.is_level_bust());
+        plane.vrate = Some(-1024);
+        assert!(!plane.is_level_bust());
+        plane.altitude = Some(34500);
+        assert!(plane.is_level_bust());
+    }
+
+    #[test]
+    fn test_autopilot_state() {
+        let mut plane = Plane::new();
+        assert_eq!(plane.autopilot_state(), "     ");
+        plane.mcp_modes = Some(McpModes {
+            vnav: false,
+            altitude_hold: true,
+            approach: false,
+        });
+        assert_eq!(plane.autopilot_state(), " -H- ");
+    }
+}
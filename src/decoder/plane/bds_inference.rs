@@ -0,0 +1,343 @@
+use super::Plane;
+use crate::decoder::{
+    CommB, Hazard, HeadingAndSpeed, Meteo, Registration, SelectedVerticalIntention, TrackAndTurn,
+};
+
+/// Lowest score of an accepted register.
+const MIN_SCORE: i32 = 2;
+
+/// Lead of the accepted register over the next best candidate.
+const MIN_LEAD: i32 = 2;
+
+/// Score of a register the capability report does not announce, never accepted.
+const NOT_CAPABLE: i32 = -10;
+
+/// Register inference of the last MB field without a BDS of its own.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BdsInference {
+    /// The accepted register, `None` when no candidate wins unambiguously.
+    pub winner: Option<(u32, u32)>,
+    /// Every candidate register with its score, the rejected alternatives included.
+    pub candidates: Vec<((u32, u32), i32)>,
+}
+
+impl Plane {
+    /// Scores every register the MB field validates as and picks the winner.
+    ///
+    /// A candidate scores for the capability report (BDS 1,7) announcing it, without the report
+    /// only in relaxed mode (BDS 4,4 is always a candidate, BDS 4,5 only with the report
+    /// announcing it), for its internal
+    /// consistency and for its agreement with the speed, track, heading and vertical rate
+    /// known from ADS-B. Disagreement costs points. The best candidate is accepted with at
+    /// least `MIN_SCORE` points and a lead of `MIN_LEAD` over the runner-up.
+    pub(crate) fn infer_bds(&self, comm_b: &CommB, relaxed: bool) -> BdsInference {
+        let capability = self.capability.1;
+        let reported = capability.flags != 0;
+        let capable = |supported: bool, required: bool| match (supported, reported, relaxed) {
+            (true, _, _) => 1,
+            (false, true, true) => -1,
+            (false, false, true) => 0,
+            (false, false, false) if !required => 0,
+            _ => NOT_CAPABLE,
+        };
+
+        let mut candidates = Vec::new();
+        if let Some(v) = &comm_b.registration {
+            candidates.push((
+                (2, 1),
+                1 + capable(capability.supports((2, 1)), true) + self.score_2_1(v),
+            ));
+        }
+        if let Some(v) = &comm_b.selected_vertical_intention {
+            candidates.push((
+                (4, 0),
+                1 + capable(capability.supports((4, 0)), true) + score_4_0(v),
+            ));
+        }
+        if let Some(v) = &comm_b.meteo {
+            candidates.push((
+                (4, 4),
+                1 + capable(capability.supports((4, 4)), false) + self.score_4_4(v),
+            ));
+        }
+        if let Some(v) = comm_b.hazard.filter(|_| capability.supports((4, 5))) {
+            candidates.push(((4, 5), 2 + self.score_4_5(&v)));
+        }
+        if let Some(v) = &comm_b.track_and_turn {
+            candidates.push((
+                (5, 0),
+                1 + capable(capability.supports((5, 0)), true) + self.score_5_0(v),
+            ));
+        }
+        if let Some(v) = &comm_b.heading_and_speed {
+            candidates.push((
+                (6, 0),
+                1 + capable(capability.supports((6, 0)), true) + self.score_6_0(v),
+            ));
+        }
+        candidates.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+
+        let winner = match candidates.as_slice() {
+            [(bds, score)] if *score >= MIN_SCORE => Some(*bds),
+            [(bds, score), (_, next), ..] if *score >= MIN_SCORE && score - next >= MIN_LEAD => {
+                Some(*bds)
+            }
+            _ => None,
+        };
+        BdsInference { winner, candidates }
+    }
+
+    /// The markings against the ones already known, the registration of an aircraft
+    /// does not change.
+    fn score_2_1(&self, v: &Registration) -> i32 {
+        let mut score = 0;
+        if let (Some(aircraft), Some(registration)) = (&v.aircraft, &self.registration) {
+            score += agreement((aircraft != registration) as u32, 0, 2);
+        }
+        if let (Some(airline), Some(registration)) = (&v.airline, &self.airline_registration) {
+            score += agreement((airline != registration) as u32, 0, 1);
+        }
+        score
+    }
+
+    /// Track and ground speed against the ADS-B velocity.
+    fn score_5_0(&self, v: &TrackAndTurn) -> i32 {
+        let mut score = 0;
+        if let Some((track, grspeed)) = self.adsb_velocity() {
+            if let Some(track_angle) = v.track_angle {
+                score += agreement(angle_difference(track_angle, track), 10, 3);
+            }
+            if let Some(ground_speed) = v.ground_speed {
+                score += agreement(ground_speed.abs_diff(grspeed), 25, 3);
+            }
+        }
+        score
+    }
+
+    /// Mach number against the indicated airspeed at the altitude of the plane, the two
+    /// vertical rates against each other and heading and vertical rate against ADS-B.
+    fn score_6_0(&self, v: &HeadingAndSpeed) -> i32 {
+        let mut score = 0;
+        if let (Some(ias), Some(mach), Some(altitude)) =
+            (v.indicated_airspeed, v.mach_number, self.altitude)
+        {
+            let difference = (mach_from_ias(ias, altitude) - mach).abs();
+            score += agreement((difference * 100.0) as u32, 5, 2);
+        }
+        if let (Some(barometric), Some(inertial)) =
+            (v.barometric_altitude_rate, v.internal_vertical_velocity)
+        {
+            score += agreement(barometric.abs_diff(inertial), 500, 1);
+        }
+        if let Some(heading) = v.magnetic_heading {
+            if let Some(adsb_heading) = self.heading.filter(|_| self.heading_source == '\u{2083}') {
+                score += agreement(angle_difference(heading, adsb_heading), 10, 3);
+            } else if let Some((track, _)) = self.adsb_velocity() {
+                score += agreement(angle_difference(heading, track), 45, 1);
+            }
+        }
+        if let Some(vrate) = self.vrate.filter(|_| self.vrate_source == ' ') {
+            if let Some(rate) = v.barometric_altitude_rate.or(v.internal_vertical_velocity) {
+                score += agreement(rate.abs_diff(vrate), 500, 2);
+            }
+        }
+        score
+    }
+
+    /// Static air temperature and pressure against the standard atmosphere at the altitude
+    /// of the plane, the wind speed within reason.
+    fn score_4_4(&self, v: &Meteo) -> i32 {
+        let mut score = 0;
+        if let (Some(temp), Some(altitude)) = (v.temp, self.altitude) {
+            let difference = (temp - isa_temperature(altitude)).abs();
+            score += agreement(difference as u32, 25, 2);
+        }
+        if let (Some(pressure), Some(altitude)) = (v.pressure, self.altitude) {
+            let difference = (pressure as f64 - isa_pressure(altitude) / 100.0).abs();
+            score += agreement(difference as u32, 30, 2);
+        }
+        if let Some((speed, _)) = v.wind {
+            score += agreement(speed, 250, 2).min(0);
+        }
+        score
+    }
+
+    /// Static air temperature and pressure against the standard atmosphere at the altitude
+    /// of the plane.
+    fn score_4_5(&self, v: &Hazard) -> i32 {
+        let mut score = 0;
+        if let Some(altitude) = self.altitude {
+            if let Some(temp) = v.temperature {
+                let difference = (temp - isa_temperature(altitude)).abs();
+                score += agreement(difference as u32, 25, 2);
+            }
+            if let Some(pressure) = v.pressure {
+                let difference = (pressure as f64 - isa_pressure(altitude) / 100.0).abs();
+                score += agreement(difference as u32, 30, 2);
+            }
+        }
+        score
+    }
+
+    /// Track and ground speed of the last ADS-B airborne velocity.
+    fn adsb_velocity(&self) -> Option<(u32, u32)> {
+        match self.track_source {
+            '\u{2081}' | '\u{2082}' => self.track.zip(self.grspeed),
+            _ => None,
+        }
+    }
+}
+
+/// Selected altitudes in whole hundreds of feet and a barometric setting near the standard.
+fn score_4_0(v: &SelectedVerticalIntention) -> i32 {
+    let mut score = 0;
+    if let Some(altitude) = v.mcp_selected_altitude.or(v.fms_selected_altitude) {
+        score += match altitude % 100 {
+            0..=16 | 84..=99 => 1,
+            _ => -1,
+        };
+    }
+    if let Some(setting) = v.barometric_pressure_setting {
+        score += match (950..=1050).contains(&setting) {
+            true => 1,
+            false => -2,
+        };
+    }
+    score
+}
+
+/// `points` when the difference is within the tolerance, the loss of `points` otherwise.
+fn agreement(difference: u32, tolerance: u32, points: i32) -> i32 {
+    match difference <= tolerance {
+        true => points,
+        false => -points,
+    }
+}
+
+fn angle_difference(a: u32, b: u32) -> u32 {
+    let difference = a.abs_diff(b) % 360;
+    difference.min(360 - difference)
+}
+
+/// Temperature of the standard atmosphere in Celsius.
+fn isa_temperature(altitude: i32) -> f64 {
+    let metres = (altitude as f64 * 0.3048).min(11000.0);
+    15.0 - 0.0065 * metres
+}
+
+/// Pressure of the standard atmosphere in Pa.
+fn isa_pressure(altitude: i32) -> f64 {
+    let metres = altitude as f64 * 0.3048;
+    match metres <= 11000.0 {
+        true => 101325.0 * (1.0 - 0.0065 * metres / 288.15).powf(5.25588),
+        false => 22632.1 * (-(metres - 11000.0) / 6341.62).exp(),
+    }
+}
+
+/// Mach number of the indicated (calibrated) airspeed in the standard atmosphere.
+fn mach_from_ias(ias: u32, altitude: i32) -> f64 {
+    let pressure = isa_pressure(altitude);
+    let impact = 101325.0 * ((1.0 + 0.2 * (ias as f64 / 661.47).powi(2)).powf(3.5) - 1.0);
+    (5.0 * ((impact / pressure + 1.0).powf(2.0 / 7.0) - 1.0)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::Capability;
+
+    fn plane() -> Plane {
+        let mut plane = Plane::new();
+        plane.capability.1 = Capability::from_data(0x020101, true, false, false, true, true);
+        plane.altitude = Some(36000);
+        plane.track = Some(290);
+        plane.grspeed = Some(470);
+        plane.track_source = '\u{2081}';
+        plane.vrate = Some(0);
+        plane
+    }
+
+    fn comm_b(track_and_turn: TrackAndTurn, heading_and_speed: HeadingAndSpeed) -> CommB {
+        CommB {
+            track_and_turn: Some(track_and_turn),
+            heading_and_speed: Some(heading_and_speed),
+            ..CommB::default()
+        }
+    }
+
+    #[test]
+    fn test_infer_bds() {
+        let heading_and_speed =
+            || HeadingAndSpeed::from_data(Some(288), Some(271), Some(0.81), Some(0), Some(32));
+        let comm_b_6_0 = comm_b(
+            TrackAndTurn::from_data(Some(10), Some(120), Some(0), Some(300), Some(310)),
+            heading_and_speed(),
+        );
+        let inference = plane().infer_bds(&comm_b_6_0, false);
+        assert_eq!(inference.winner, Some((6, 0)));
+        assert_eq!(inference.candidates.len(), 2);
+
+        let comm_b_5_0 = comm_b(
+            TrackAndTurn::from_data(Some(0), Some(291), Some(0), Some(468), Some(460)),
+            HeadingAndSpeed::from_data(Some(90), Some(500), Some(0.3), Some(3000), None),
+        );
+        assert_eq!(plane().infer_bds(&comm_b_5_0, false).winner, Some((5, 0)));
+
+        let mut unknown = Plane::new();
+        unknown.capability.1 = plane().capability.1;
+        let inference = unknown.infer_bds(&comm_b_6_0, false);
+        assert_eq!(inference.winner, None);
+        assert_eq!(inference.candidates, vec![((6, 0), 3), ((5, 0), 2)]);
+
+        let comm_b_4_0 = CommB {
+            selected_vertical_intention: Some(SelectedVerticalIntention::from_data(
+                Some(36000),
+                None,
+                Some(1013),
+                None,
+                None,
+            )),
+            ..CommB::default()
+        };
+        assert_eq!(plane().infer_bds(&comm_b_4_0, false).winner, None);
+        assert_eq!(plane().infer_bds(&comm_b_4_0, true).winner, Some((4, 0)));
+
+        let comm_b_2_1 = CommB {
+            registration: Some(Registration {
+                aircraft: Some("EIDEA".to_string()),
+                airline: None,
+            }),
+            ..CommB::default()
+        };
+        assert_eq!(plane().infer_bds(&comm_b_2_1, true).winner, None);
+        let mut registered = plane();
+        registered.capability.1.flags |= 0x010000;
+        assert_eq!(
+            registered.infer_bds(&comm_b_2_1, false).winner,
+            Some((2, 1))
+        );
+        registered.registration = Some("EIDEB".to_string());
+        assert_eq!(registered.infer_bds(&comm_b_2_1, false).winner, None);
+
+        let comm_b_4_5 = CommB {
+            hazard: Some(Hazard {
+                temperature: Some(-55.0),
+                pressure: Some(227),
+                ..Hazard::default()
+            }),
+            ..CommB::default()
+        };
+        assert!(plane().infer_bds(&comm_b_4_5, true).candidates.is_empty());
+        let mut reporting = plane();
+        reporting.capability.1.flags |= 0x000400;
+        assert_eq!(reporting.infer_bds(&comm_b_4_5, false).winner, Some((4, 5)));
+    }
+
+    #[test]
+    fn test_mach_from_ias() {
+        assert!((mach_from_ias(250, 0) - 0.378).abs() < 0.005);
+        assert!((mach_from_ias(271, 36000) - 0.81).abs() < 0.02);
+        assert_eq!(angle_difference(350, 10), 20);
+        assert!((isa_pressure(36000) / 100.0 - 227.0).abs() < 1.0);
+    }
+}
@@ -0,0 +1,21 @@
+use crate::decoder::{plane::from_downlink::UpdateFromDownlink, Acs, Plane};
+use chrono::{DateTime, Utc};
+
+impl UpdateFromDownlink<Acs> for Plane {
+    fn update_from_downlink(&mut self, dl: &Acs, _relaxed: bool, timestamp: DateTime<Utc>) {
+        if dl.icao.is_some() {
+            self.timestamp = timestamp;
+            if let Some(df) = dl.df {
+                self.last_df = df;
+            }
+            if dl.altitude.is_some() {
+                self.altitude = dl.altitude;
+                self.altitude_source = ' ';
+            }
+            if let Some(ra) = dl.resolution_advisory {
+                self.threat_encounter = ra.threat_encounter();
+                self.resolution_advisory = Some(ra);
+            }
+        }
+    }
+}
@@ -1,9 +1,21 @@
 use crate::decoder::{plane::from_downlink::UpdateFromDownlink, Ext, Plane};
+use chrono::{DateTime, Utc};
 
 impl UpdateFromDownlink<Ext> for Plane {
-    fn update_from_downlink(&mut self, dl: &Ext) {
+    fn update_from_downlink(&mut self, dl: &Ext, _relaxed: bool, timestamp: DateTime<Utc>) {
         if dl.icao.is_some() {
+            self.timestamp = timestamp;
+            if let Some(df) = dl.df {
+                self.last_df = df;
+            }
             self.last_type_code = dl.message_type.0;
+            if dl.df == Some(17) {
+                self.extended_squitter = true;
+            }
+            if dl.control_field == Some(3) {
+                self.amend_from_coarse_tisb(dl);
+                return;
+            }
             match dl.message_type.0 {
                 1..=4 => {
                     self.amend_from_ext_1_4(dl);
@@ -20,6 +32,14 @@ impl UpdateFromDownlink<Ext> for Plane {
                 20..=22 => {
                     self.amend_from_ext_20_22(dl);
                 }
+                28 => {
+                    self.amend_from_ext_28(dl);
+                }
+                29 => {
+                    if let Some(target_state) = dl.target_state.as_deref() {
+                        self.update_target_state(target_state);
+                    }
+                }
                 31 => {
                     self.amend_from_ext_31(dl);
                 }
@@ -30,6 +50,22 @@ impl UpdateFromDownlink<Ext> for Plane {
 }
 
 impl Plane {
+    fn amend_from_coarse_tisb(&mut self, dl: &Ext) {
+        self.altitude = dl.altitude;
+        self.altitude_source = ' ';
+        self.track = dl.track;
+        self.track_source = ' ';
+        self.grspeed = dl.grspeed;
+        if let Some((cpr_form, cpr_lat, cpr_lon)) = dl.cpr {
+            self.cpr_lat[cpr_form as usize] = cpr_lat;
+            self.cpr_lon[cpr_form as usize] = cpr_lon;
+            self.cpr_time[cpr_form as usize] = self.timestamp;
+
+            // coarse TIS-B reports airborne positions only
+            self.update_position(9, cpr_form);
+        }
+    }
+
     fn amend_from_ext_1_4(&mut self, dl: &Ext) {
         if dl.ais.is_some() {
             self.ais.clone_from(&dl.ais);
@@ -43,6 +79,7 @@ impl Plane {
         self.altitude_source = '\u{2070}';
         self.track = dl.track;
         self.track_source = dl.track_source.unwrap_or(' ');
+        self.update_integrity(dl.message_type.0, None);
         self.amend_cpr(dl);
     }
 
@@ -50,24 +87,26 @@ impl Plane {
         self.altitude = dl.altitude;
         self.altitude_source = ' ';
         self.surveillance_status = dl.surveillance_status.unwrap_or(' ');
+        self.update_integrity(dl.message_type.0, dl.nic_supplement_b);
         self.amend_cpr(dl);
     }
 
     fn amend_from_ext_19(&mut self, dl: &Ext) {
         self.vrate = dl.vrate;
         self.vrate_source = ' ';
+        self.nacv = dl.nacv;
         if let Some(altitude_delta) = dl.altitude_delta {
             if let Some(altitude) = self.altitude {
-                self.altitude_gnss = Some((altitude as i32 + altitude_delta) as u32);
+                self.altitude_gnss = Some(altitude + altitude_delta);
             }
         }
         match dl.message_type.1 {
             1 => {
-                (self.track, self.grspeed) = (self.track, self.grspeed);
+                (self.track, self.grspeed) = (dl.track, dl.grspeed);
                 self.track_source = '\u{2081}';
             }
             2 => {
-                (self.track, self.grspeed) = (self.track, self.grspeed);
+                (self.track, self.grspeed) = (dl.track, dl.grspeed);
                 self.track_source = '\u{2082}';
             }
             3 | 4 => {
@@ -82,10 +121,32 @@ impl Plane {
     fn amend_from_ext_20_22(&mut self, dl: &Ext) {
         self.altitude_gnss = dl.altitude_gnss;
         self.surveillance_status = dl.surveillance_status.unwrap_or(' ');
+        self.update_integrity(dl.message_type.0, dl.nic_supplement_b);
+    }
+
+    fn amend_from_ext_28(&mut self, dl: &Ext) {
+        match dl.message_type.1 {
+            1 => {
+                self.emergency = dl.emergency;
+                if dl.squawk.is_some() {
+                    self.squawk = dl.squawk;
+                }
+            }
+            2 => {
+                if let Some(ra) = dl.resolution_advisory {
+                    self.threat_encounter = ra.threat_encounter();
+                    self.resolution_advisory = Some(ra);
+                }
+            }
+            _ => {}
+        }
     }
 
     fn amend_from_ext_31(&mut self, dl: &Ext) {
         self.adsb_version = dl.adsb_version;
+        if let Some(operational_status) = dl.operational_status.as_deref() {
+            self.update_operational_status(operational_status);
+        }
     }
 
     fn amend_cpr(&mut self, dl: &Ext) {
@@ -98,3 +159,26 @@ impl Plane {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::decoder::{message, Downlink, Plane, DF};
+
+    #[test]
+    fn test_amend_from_ext_19() {
+        let squitter = "8D485020994409940838175B284F";
+        if let Some(message) = message(squitter) {
+            match DF::from_message(&message) {
+                Ok(DF::EXT(ext)) => {
+                    let mut plane = Plane::new();
+                    plane.amend_from_ext_19(&ext);
+                    assert!(ext.track.is_some() && ext.grspeed.is_some());
+                    assert_eq!(plane.track, ext.track);
+                    assert_eq!(plane.grspeed, ext.grspeed);
+                    assert_eq!(plane.track_source, '\u{2081}');
+                }
+                _ => panic!("DF17 is not an extended squitter"),
+            }
+        }
+    }
+}
@@ -1,9 +1,214 @@
-use crate::decoder::{plane::from_downlink::UpdateFromDownlink, Mds, Plane};
+use crate::decoder::plane::target_altitude_source_char;
+use crate::decoder::{plane::from_downlink::UpdateFromDownlink, CommB, Hazard, Mds, Plane};
+use chrono::{DateTime, Utc};
+use log::debug;
 
 impl UpdateFromDownlink<Mds> for Plane {
-    fn update_from_downlink(&mut self, dl: &Mds) {
+    fn update_from_downlink(&mut self, dl: &Mds, relaxed: bool, timestamp: DateTime<Utc>) {
         if let Some(v) = dl.icao {
             self.icao = v;
+            self.timestamp = timestamp;
+            if let Some(df) = dl.df {
+                self.last_df = df;
+            }
+            if dl.altitude.is_some() {
+                self.altitude = dl.altitude;
+                self.altitude_source = ' ';
+            }
+            if dl.squawk.is_some() {
+                self.squawk = dl.squawk;
+            }
+            if let Some(flight_status) = &dl.flight_status {
+                self.update_flight_status(flight_status);
+            }
+            self.update_interrogator(dl.interrogator);
+            if let Some(comm_b) = dl
+                .comm_b
+                .as_ref()
+                .filter(|_| relaxed || self.capability.0 > 3)
+            {
+                self.amend_from_comm_b(comm_b, relaxed);
+            }
         }
     }
 }
+
+impl Plane {
+    /// Applies the Comm-B register, the registers without a BDS of their own are taken
+    /// only when the inference finds an unambiguous winner.
+    pub(crate) fn amend_from_comm_b(&mut self, comm_b: &CommB, relaxed: bool) {
+        match comm_b.bds {
+            (1, 0) if comm_b.data_link_capability.is_some() => {
+                self.data_link_capability = comm_b.data_link_capability;
+            }
+            (2, 0) => {
+                self.ais = comm_b.ais.clone();
+            }
+            (3, 0) => {
+                self.threat_encounter = comm_b.threat_encounter;
+                if comm_b.resolution_advisory.is_some() {
+                    self.resolution_advisory = comm_b.resolution_advisory;
+                }
+            }
+            (0, 0) => {
+                if let Some(capability) = comm_b.capability {
+                    self.capability.1 = capability;
+                    debug!(
+                        "ICAO:{:06X} BDS:1.7, C:{:024b} {:?}",
+                        self.icao,
+                        capability.flags,
+                        capability.registers()
+                    );
+                    return;
+                }
+                let inference = self.infer_bds(comm_b, relaxed);
+                match inference.winner {
+                    Some((2, 1)) => self.amend_from_bds_2_1(comm_b),
+                    Some((4, 0)) => self.amend_from_bds_4_0(comm_b),
+                    Some((4, 4)) => self.amend_from_bds_4_4(comm_b),
+                    Some((4, 5)) => self.amend_from_bds_4_5(comm_b),
+                    Some((5, 0)) => self.amend_from_bds_5_0(comm_b),
+                    Some((6, 0)) => self.amend_from_bds_6_0(comm_b),
+                    _ => {
+                        if !inference.candidates.is_empty() {
+                            debug!(
+                                "ICAO:{:06X} ambiguous BDS {:?}",
+                                self.icao, inference.candidates
+                            );
+                        }
+                    }
+                }
+                self.bds_inference = Some(inference);
+            }
+            _ => {}
+        }
+    }
+
+    fn amend_from_bds_2_1(&mut self, comm_b: &CommB) {
+        if let Some(registration) = &comm_b.registration {
+            if registration.aircraft.is_some() {
+                self.registration = registration.aircraft.clone();
+            }
+            if registration.airline.is_some() {
+                self.airline_registration = registration.airline.clone();
+            }
+        }
+    }
+
+    fn amend_from_bds_4_0(&mut self, comm_b: &CommB) {
+        if let Some(value) = &comm_b.selected_vertical_intention {
+            self.mcp_selected_altitude = value.mcp_selected_altitude;
+            self.fms_selected_altitude = value.fms_selected_altitude;
+            self.selected_altitude = match value.target_altitude_source {
+                Some(3) => value.fms_selected_altitude,
+                Some(2) => value.mcp_selected_altitude,
+                _ => value.mcp_selected_altitude.or(value.fms_selected_altitude),
+            };
+            self.target_altitude_source = target_altitude_source_char(value.target_altitude_source);
+            self.barometric_pressure_setting = value.barometric_pressure_setting;
+            if value.mcp_modes.is_some() {
+                self.mcp_modes = value.mcp_modes;
+            }
+        }
+    }
+
+    fn amend_from_bds_4_4(&mut self, comm_b: &CommB) {
+        if let Some(meteo) = &comm_b.meteo {
+            if meteo.temp.is_some() {
+                self.temperature = meteo.temp;
+            }
+            if meteo.wind.is_some() {
+                self.wind = meteo.wind;
+            }
+            if meteo.humidity.is_some() {
+                self.humidity = meteo.humidity;
+            }
+            if meteo.turbulence.is_some() {
+                self.turbulence = meteo.turbulence;
+            }
+            if meteo.pressure.is_some() {
+                self.pressure = meteo.pressure;
+            }
+            self.record_meteo(meteo);
+        }
+    }
+
+    fn amend_from_bds_4_5(&mut self, comm_b: &CommB) {
+        if let Some(hazard) = comm_b.hazard {
+            if hazard.temperature.is_some() {
+                self.temperature = hazard.temperature;
+            }
+            if hazard.pressure.is_some() {
+                self.pressure = hazard.pressure;
+            }
+            self.hazard = Some(hazard);
+            self.hazard_timestamp = Some(self.timestamp);
+            self.hazard_reported = true;
+        }
+    }
+
+    /// The hazard report received since the last call, once.
+    pub fn take_hazard_report(&mut self) -> Option<Hazard> {
+        match std::mem::take(&mut self.hazard_reported) {
+            true => self.hazard,
+            false => None,
+        }
+    }
+
+    fn amend_from_bds_5_0(&mut self, comm_b: &CommB) {
+        if let Some(result) = &comm_b.track_and_turn {
+            self.roll_angle = result.roll_angle;
+            self.track = result.track_angle;
+            self.track_angle_rate = result.track_angle_rate;
+            self.grspeed = result.ground_speed;
+            self.true_airspeed = result.true_airspeed;
+            self.bds_5_0_timestamp = Some(self.timestamp);
+            self.track_source = '\u{2085}';
+            self.track_timestamp = Some(self.timestamp);
+        }
+    }
+
+    fn amend_from_bds_6_0(&mut self, comm_b: &CommB) {
+        if let Some(result) = &comm_b.heading_and_speed {
+            self.heading = result.magnetic_heading;
+            self.indicated_airspeed = result.indicated_airspeed;
+            self.mach_number = result.mach_number;
+            self.vrate = match result.barometric_altitude_rate.is_some() {
+                true => {
+                    self.vrate_source = '\u{2086}';
+                    result.barometric_altitude_rate
+                }
+                _ => {
+                    self.vrate_source = '\u{2071}';
+                    result.internal_vertical_velocity
+                }
+            };
+            self.heading_source = '\u{2086}';
+            self.heading_timestamp = Some(self.timestamp);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_hazard_report() {
+        let comm_b = CommB {
+            hazard: Some(Hazard {
+                temperature: Some(-55.0),
+                ..Hazard::default()
+            }),
+            ..CommB::default()
+        };
+        let mut plane = Plane::new();
+        assert_eq!(plane.take_hazard_report(), None);
+        plane.amend_from_bds_4_5(&comm_b);
+        assert_eq!(plane.take_hazard_report(), comm_b.hazard);
+        assert_eq!(plane.take_hazard_report(), None);
+        // A second report with the same frame time is reported again
+        plane.amend_from_bds_4_5(&comm_b);
+        assert_eq!(plane.take_hazard_report(), comm_b.hazard);
+    }
+}
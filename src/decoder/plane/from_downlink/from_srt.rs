@@ -1,8 +1,13 @@
 use crate::decoder::{plane::from_downlink::UpdateFromDownlink, Plane, Srt};
+use chrono::{DateTime, Utc};
 
 impl UpdateFromDownlink<Srt> for Plane {
-    fn update_from_downlink(&mut self, dl: &Srt) {
+    fn update_from_downlink(&mut self, dl: &Srt, _relaxed: bool, timestamp: DateTime<Utc>) {
         if dl.icao.is_some() {
+            self.timestamp = timestamp;
+            if let Some(df) = dl.df {
+                self.last_df = df;
+            }
             if dl.df == Some(4) && dl.altitude.is_some() {
                 self.altitude = dl.altitude;
                 self.altitude_source = ' ';
@@ -10,6 +15,10 @@ impl UpdateFromDownlink<Srt> for Plane {
             if dl.df == Some(5) && dl.squawk.is_some() {
                 self.squawk = dl.squawk;
             }
+            if let Some(flight_status) = &dl.flight_status {
+                self.update_flight_status(flight_status);
+            }
+            self.update_interrogator(dl.interrogator);
             if dl.df == Some(11) {
                 if let Some(v) = dl.capability {
                     self.capability.0 = v;
@@ -1,19 +1,22 @@
+mod from_acs;
 mod from_ext;
 mod from_mds;
 mod from_srt;
 
 use crate::decoder::{Plane, DF};
+use chrono::{DateTime, Utc};
 
 pub trait UpdateFromDownlink<T> {
-    fn update_from_downlink(&mut self, dl: &T);
+    fn update_from_downlink(&mut self, dl: &T, relaxed: bool, timestamp: DateTime<Utc>);
 }
 
 impl UpdateFromDownlink<DF> for Plane {
-    fn update_from_downlink(&mut self, dl: &DF) {
+    fn update_from_downlink(&mut self, dl: &DF, relaxed: bool, timestamp: DateTime<Utc>) {
         match dl {
-            DF::SRT(v) => self.update_from_downlink(v),
-            DF::EXT(v) => self.update_from_downlink(v),
-            DF::MDS(v) => self.update_from_downlink(v),
+            DF::SRT(v) => self.update_from_downlink(v, relaxed, timestamp),
+            DF::ACS(v) => self.update_from_downlink(v, relaxed, timestamp),
+            DF::EXT(v) => self.update_from_downlink(v, relaxed, timestamp),
+            DF::MDS(v) => self.update_from_downlink(v, relaxed, timestamp),
         }
     }
 }
@@ -0,0 +1,15 @@
+use super::Plane;
+use crate::decoder;
+
+impl Plane {
+    pub(super) fn update_from_air_air(&mut self, message: &[u32], df: u32) {
+        if let Some(altitude) = decoder::altitude(message, df) {
+            self.altitude = Some(altitude);
+            self.altitude_source = ' ';
+        }
+        if let Some(ra) = decoder::resolution_advisory(message, df) {
+            self.threat_encounter = ra.threat_encounter();
+            self.resolution_advisory = Some(ra);
+        }
+    }
+}
@@ -11,6 +11,19 @@ impl Plane {
             self.squawk = decoder::squawk(message);
         }
 
+        if matches!(df, 4 | 5 | 20 | 21) {
+            if let Some(flight_status) = decoder::flight_status(message) {
+                self.update_flight_status(&flight_status);
+            }
+        }
+
+        if matches!(df, 4 | 5 | 11 | 20 | 21) {
+            self.update_interrogator(decoder::interrogator(message, df));
+        }
+        if df == 17 {
+            self.extended_squitter = true;
+        }
+
         if df == 11 || df == 17 {
             self.capability.0 = decoder::ca(message);
         }
@@ -22,7 +22,15 @@ impl Plane {
                 self.update_from_ext_19(message, message_subtype);
             }
             20..=22 => {
-                self.update_from_ext_20_22(message);
+                self.update_from_ext_20_22(message, message_type);
+            }
+            28 => {
+                self.update_from_ext_28(message, message_subtype, df);
+            }
+            29 => {
+                if let Some(target_state) = decoder::target_state(message) {
+                    self.update_target_state(&target_state);
+                }
             }
             31 => {
                 self.update_from_ext_31(message);
@@ -43,6 +51,21 @@ impl Plane {
         }
     }
 
+    pub(super) fn update_from_coarse_tisb(&mut self, message: &[u32]) {
+        self.altitude = decoder::altitude_coarse(message);
+        self.altitude_source = ' ';
+        (self.track, self.grspeed) = decoder::coarse_track_and_groundspeed(message);
+        self.track_source = ' ';
+        if let Some((cpr_form, cpr_lat, cpr_lon)) = decoder::coarse_cpr(message) {
+            self.cpr_lat[cpr_form as usize] = cpr_lat;
+            self.cpr_lon[cpr_form as usize] = cpr_lon;
+            self.cpr_time[cpr_form as usize] = self.timestamp;
+
+            // coarse TIS-B reports airborne positions only
+            self.update_position(9, cpr_form);
+        }
+    }
+
     pub(super) fn update_from_ext_1_4(
         &mut self,
         message: &[u32],
@@ -59,6 +82,7 @@ impl Plane {
         self.altitude_source = '\u{2070}';
         self.track = decoder::ground_track(message);
         self.track_source = ' ';
+        self.update_integrity(message_type, None);
         self.update_cpr(message, message_type);
     }
 
@@ -66,15 +90,17 @@ impl Plane {
         self.altitude = decoder::altitude(message, df);
         self.altitude_source = ' ';
         self.surveillance_status = decoder::surveillance_status(message);
+        self.update_integrity(message_type, decoder::nic_supplement_b(message));
         self.update_cpr(message, message_type);
     }
 
     pub(super) fn update_from_ext_19(&mut self, message: &[u32], message_subtype: u32) {
         self.vrate = decoder::vertical_rate(message);
         self.vrate_source = ' ';
+        self.nacv = decoder::nacv(message);
         if let Some(altitude) = self.altitude {
             if let Some(altitude_delta) = decoder::altitude_delta(message) {
-                self.altitude_gnss = Some((altitude as i32 + altitude_delta) as u32);
+                self.altitude_gnss = Some(altitude + altitude_delta);
             }
         }
         match message_subtype {
@@ -96,12 +122,34 @@ impl Plane {
         }
     }
 
-    pub(super) fn update_from_ext_20_22(&mut self, message: &[u32]) {
+    pub(super) fn update_from_ext_20_22(&mut self, message: &[u32], message_type: u32) {
         self.altitude_gnss = decoder::altitude_gnss(message);
         self.surveillance_status = decoder::surveillance_status(message);
+        self.update_integrity(message_type, decoder::nic_supplement_b(message));
+    }
+
+    pub(super) fn update_from_ext_28(&mut self, message: &[u32], message_subtype: u32, df: u32) {
+        match message_subtype {
+            1 => {
+                self.emergency = decoder::emergency(message);
+                if let Some(squawk) = decoder::emergency_squawk(message) {
+                    self.squawk = Some(squawk);
+                }
+            }
+            2 => {
+                if let Some(ra) = decoder::resolution_advisory(message, df) {
+                    self.threat_encounter = ra.threat_encounter();
+                    self.resolution_advisory = Some(ra);
+                }
+            }
+            _ => {}
+        }
     }
 
     pub(super) fn update_from_ext_31(&mut self, message: &[u32]) {
         self.adsb_version = decoder::version(message);
+        if let Some(operational_status) = decoder::operational_status(message) {
+            self.update_operational_status(&operational_status);
+        }
     }
 }
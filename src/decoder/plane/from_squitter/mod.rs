@@ -1,18 +1,27 @@
 use super::Plane;
-use chrono::Utc;
+use crate::decoder;
+use chrono::{DateTime, Utc};
+mod from_air_air;
 mod from_bcast;
 mod from_ext;
 mod from_mode_s;
 
 impl Plane {
-    pub fn update(&mut self, message: &[u32], df: u32, relaxed: bool) {
-        self.timestamp = Utc::now();
+    pub fn update(&mut self, message: &[u32], df: u32, relaxed: bool, timestamp: DateTime<Utc>) {
+        self.timestamp = timestamp;
         self.last_df = df;
 
         self.update_from_bcast(message, df);
 
-        if df == 17 || df == 18 {
-            self.update_from_ext(message, df);
+        match df {
+            16 => self.update_from_air_air(message, df),
+            17 => self.update_from_ext(message, df),
+            18 => match decoder::cf(message) {
+                3 => self.update_from_coarse_tisb(message),
+                4 | 7 => {}
+                _ => self.update_from_ext(message, df),
+            },
+            _ => {}
         }
 
         if (relaxed || (self.capability.0 > 3)) && (df == 20 || df == 21) {
@@ -0,0 +1,79 @@
+use super::Plane;
+use crate::decoder::Meteo;
+use chrono::{DateTime, Utc};
+
+/// Reports kept per plane, the oldest ones are dropped first.
+const METEO_HISTORY: usize = 32;
+
+/// Age of the last position fix still kept with a report, in seconds.
+const MAX_POSITION_AGE: i64 = 10;
+
+/// Meteorological routine air report (BDS 4,4) with the time, position and altitude
+/// of the plane when it was received, no position when the last fix is older than
+/// `MAX_POSITION_AGE`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeteoReport {
+    pub timestamp: DateTime<Utc>,
+    pub position: Option<(f64, f64)>,
+    pub altitude: Option<i32>,
+    pub meteo: Meteo,
+}
+
+impl Plane {
+    pub(crate) fn record_meteo(&mut self, meteo: &Meteo) {
+        if self.meteo_history.len() == METEO_HISTORY {
+            self.meteo_history.pop_front();
+        }
+        self.meteo_history.push_back(MeteoReport {
+            timestamp: self.timestamp,
+            position: self
+                .position_timestamp
+                .filter(|position_timestamp| {
+                    (0..=MAX_POSITION_AGE).contains(
+                        &self
+                            .timestamp
+                            .signed_duration_since(*position_timestamp)
+                            .num_seconds(),
+                    )
+                })
+                .map(|_| (self.lat, self.lon)),
+            altitude: self.altitude,
+            meteo: *meteo,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_record_meteo() {
+        let mut plane = Plane::new();
+        plane.altitude = Some(36000);
+        let meteo = Meteo {
+            temp: Some(-50.0),
+            ..Meteo::default()
+        };
+        for _ in 0..=METEO_HISTORY {
+            plane.record_meteo(&meteo);
+        }
+        assert_eq!(plane.meteo_history.len(), METEO_HISTORY);
+        let report = plane.meteo_history.back().unwrap();
+        assert_eq!(report.position, None);
+        assert_eq!(report.altitude, Some(36000));
+        assert_eq!(report.meteo.temp, Some(-50.0));
+
+        (plane.lat, plane.lon) = (51.5, -0.1);
+        plane.position_timestamp = Some(plane.timestamp - Duration::seconds(5));
+        plane.record_meteo(&meteo);
+        assert_eq!(
+            plane.meteo_history.back().unwrap().position,
+            Some((51.5, -0.1))
+        );
+        plane.position_timestamp = Some(plane.timestamp - Duration::seconds(120));
+        plane.record_meteo(&meteo);
+        assert_eq!(plane.meteo_history.back().unwrap().position, None);
+    }
+}
@@ -1,32 +1,64 @@
+mod autopilot;
+mod bds_inference;
 mod from_downlink;
 mod from_squitter;
+mod meteo_history;
+mod plausibility;
 mod simple_display;
 
-use crate::decoder::Capability;
+use crate::decoder::{
+    self, AddressType, AutopilotModes, Capability, DataLinkCapability, Emergency, FlightStatus,
+    Frame, Hazard, Interrogator, McpModes, OperationalStatus, ResolutionAdvisory, TargetState,
+};
 use chrono::{DateTime, Utc};
+use log::warn;
+use std::collections::{BTreeMap, VecDeque};
 use std::fmt::{self, Display};
 
+pub use bds_inference::BdsInference;
 pub use from_downlink::UpdateFromDownlink;
-pub use simple_display::format_simple_display;
+pub use meteo_history::MeteoReport;
+pub use plausibility::{position_rejects, PositionReject};
+pub use simple_display::{format_simple_display, DisplayFlags};
 mod update_position;
 
 use super::DF;
 
 pub struct Plane {
     pub icao: u32,
+    pub address_type: AddressType,
     pub capability: (u32, Capability),
+    pub data_link_capability: Option<DataLinkCapability>,
     pub category: (u32, u32),
     pub reg: &'static str,
+    /// Aircraft registration marking (BDS 2,1).
+    pub registration: Option<String>,
+    /// ICAO airline registration marking (BDS 2,1).
+    pub airline_registration: Option<String>,
     pub ais: Option<String>,
-    pub altitude: Option<u32>,
-    pub altitude_gnss: Option<u32>,
+    pub altitude: Option<i32>,
+    pub altitude_gnss: Option<i32>,
     pub altitude_source: char,
     pub selected_altitude: Option<u32>,
+    pub mcp_selected_altitude: Option<u32>,
+    pub fms_selected_altitude: Option<u32>,
     pub barometric_pressure_setting: Option<u32>,
     pub target_altitude_source: char,
+    pub selected_heading: Option<u32>,
+    pub autopilot_modes: Option<AutopilotModes>,
+    /// MCP/FCU mode bits of the selected vertical intention (BDS 4,0).
+    pub mcp_modes: Option<McpModes>,
+    pub tcas_operational: Option<bool>,
     pub squawk: Option<u32>,
+    pub emergency: Option<Emergency>,
+    pub alert: bool,
+    pub spi: bool,
+    pub on_ground: Option<bool>,
     pub surveillance_status: char,
     pub threat_encounter: Option<char>,
+    pub resolution_advisory: Option<ResolutionAdvisory>,
+    /// Last advisory taken by `take_advisory_change`.
+    pub last_advisory: Option<ResolutionAdvisory>,
     pub vrate: Option<i32>,
     pub vrate_source: char,
     pub cpr_lat: [u32; 2],
@@ -53,37 +85,73 @@ pub struct Plane {
     pub turbulence: Option<u32>,
     pub humidity: Option<u32>,
     pub pressure: Option<u32>,
+    /// Recent meteorological routine air reports (BDS 4,4).
+    pub meteo_history: VecDeque<MeteoReport>,
+    /// Last meteorological hazard report (BDS 4,5).
+    pub hazard: Option<Hazard>,
+    pub hazard_timestamp: Option<DateTime<Utc>>,
+    /// A hazard report arrived since the last `take_hazard_report`.
+    pub hazard_reported: bool,
     pub timestamp: DateTime<Utc>,
     pub position_timestamp: Option<DateTime<Utc>>,
+    pub consecutive_speed_rejects: u32,
     pub track_timestamp: Option<DateTime<Utc>>,
     pub heading_timestamp: Option<DateTime<Utc>>,
     pub last_type_code: u32,
     pub last_df: u32,
     pub adsb_version: Option<u32>,
+    pub operational_status: Option<OperationalStatus>,
+    pub nuc_p: Option<u32>,
+    pub nic: Option<u32>,
+    pub nacp: Option<u32>,
+    pub nacv: Option<u32>,
+    pub sil: Option<u32>,
+    pub bds_inference: Option<BdsInference>,
+    pub interrogators: BTreeMap<Interrogator, DateTime<Utc>>,
+    pub acquisition_squitter: bool,
+    pub extended_squitter: bool,
+    pub mlat_timestamp: Option<u64>,
+    pub signal: Option<u8>,
 }
 
 impl Plane {
     pub fn new() -> Self {
         Plane {
             icao: 0,
+            address_type: AddressType::Icao,
             capability: (0, Capability::default()),
+            data_link_capability: None,
             category: (0, 0),
             reg: "",
+            registration: None,
+            airline_registration: None,
             ais: None,
             altitude: None,
             altitude_gnss: None,
             altitude_source: ' ',
             selected_altitude: None,
+            mcp_selected_altitude: None,
+            fms_selected_altitude: None,
             barometric_pressure_setting: None,
             target_altitude_source: ' ',
+            selected_heading: None,
+            autopilot_modes: None,
+            mcp_modes: None,
+            tcas_operational: None,
             squawk: None,
+            emergency: None,
+            alert: false,
+            spi: false,
+            on_ground: None,
             surveillance_status: ' ',
             threat_encounter: None,
+            resolution_advisory: None,
+            last_advisory: None,
             vrate: None,
             vrate_source: '_',
             cpr_lat: [0, 0],
             cpr_lon: [0, 0],
-            cpr_time: [Utc::now(), Utc::now()],
+            cpr_time: [DateTime::default(), DateTime::default()],
             lat: 0.0,
             lon: 0.0,
             distance_from_observer: None,
@@ -105,31 +173,188 @@ impl Plane {
             turbulence: None,
             humidity: None,
             pressure: None,
-            timestamp: Utc::now(),
+            meteo_history: VecDeque::new(),
+            hazard: None,
+            hazard_timestamp: None,
+            hazard_reported: false,
+            timestamp: DateTime::default(),
             position_timestamp: None,
+            consecutive_speed_rejects: 0,
             track_timestamp: None,
             heading_timestamp: None,
             last_type_code: 0,
             last_df: 0,
             adsb_version: None,
+            operational_status: None,
+            nuc_p: None,
+            nic: None,
+            nacp: None,
+            nacv: None,
+            sil: None,
+            bds_inference: None,
+            interrogators: BTreeMap::new(),
+            acquisition_squitter: false,
+            extended_squitter: false,
+            mlat_timestamp: None,
+            signal: None,
         }
     }
 
-    pub fn from_message(message: &[u32], df: u32, icao: u32, relaxed: bool) -> Self {
+    pub fn from_message(
+        message: &[u32],
+        df: u32,
+        icao: u32,
+        relaxed: bool,
+        timestamp: DateTime<Utc>,
+    ) -> Self {
         let mut plane = Plane::new();
         plane.icao = icao;
-        (_, plane.reg) = super::icao_to_country(icao);
-        plane.update(message, df, relaxed);
+        plane.address_type = decoder::address_type(message, df).unwrap_or_default();
+        if plane.address_type.is_icao() {
+            (_, plane.reg) = super::icao_to_country(icao);
+        }
+        plane.update(message, df, relaxed, timestamp);
         plane
     }
 
-    pub fn from_downlink(dl: &DF, icao: u32) -> Self {
+    pub fn from_downlink(dl: &DF, icao: u32, relaxed: bool, timestamp: DateTime<Utc>) -> Self {
         let mut plane = Plane::new();
         plane.icao = icao;
-        (_, plane.reg) = super::icao_to_country(icao);
-        plane.update_from_downlink(dl);
+        plane.address_type = dl.address_type();
+        if plane.address_type.is_icao() {
+            (_, plane.reg) = super::icao_to_country(icao);
+        }
+        plane.update_from_downlink(dl, relaxed, timestamp);
         plane
     }
+
+    /// Keeps the receiver timestamp and signal level of the last frame received from the plane.
+    pub fn update_reception(&mut self, frame: &Frame) {
+        if frame.timestamp.is_some() {
+            self.mlat_timestamp = frame.timestamp;
+        }
+        if frame.signal.is_some() {
+            self.signal = frame.signal;
+        }
+    }
+}
+
+impl Plane {
+    /// Merges the ADS-B target state and status (TC 29) into the selected values of the plane.
+    pub(crate) fn update_target_state(&mut self, target_state: &TargetState) {
+        if target_state.selected_altitude.is_some() {
+            self.selected_altitude = target_state.selected_altitude;
+            self.target_altitude_source =
+                target_altitude_source_char(target_state.target_altitude_source);
+        }
+        if target_state.barometric_pressure_setting.is_some() {
+            self.barometric_pressure_setting = target_state.barometric_pressure_setting;
+        }
+        if target_state.selected_heading.is_some() {
+            self.selected_heading = target_state.selected_heading;
+        }
+        if target_state.autopilot_modes.is_some() {
+            self.autopilot_modes = target_state.autopilot_modes;
+        }
+        self.tcas_operational = target_state.tcas_operational;
+        if target_state.nacp.is_some() {
+            self.nacp = target_state.nacp;
+        }
+        if target_state.sil.is_some() {
+            self.sil = target_state.sil;
+        }
+    }
+
+    /// Keeps the alert, SPI and on-ground state of the flight status (FS).
+    ///
+    /// The raise of the alert (a Mode A code change) is logged as an event.
+    pub(crate) fn update_flight_status(&mut self, flight_status: &FlightStatus) {
+        if flight_status.alert && !self.alert {
+            warn!(
+                "ICAO:{:06X} alert, squawk {}",
+                self.icao,
+                self.squawk
+                    .map(|squawk| format!("{:04}", squawk))
+                    .unwrap_or_default()
+            );
+        }
+        self.alert = flight_status.alert;
+        self.spi = flight_status.spi;
+        if flight_status.on_ground.is_some() {
+            self.on_ground = flight_status.on_ground;
+        }
+    }
+
+    /// Keeps the time of the last reply to each interrogator, a DF11 with II 0 marks the
+    /// spontaneous acquisition squitter instead.
+    pub(crate) fn update_interrogator(&mut self, interrogator: Option<Interrogator>) {
+        match interrogator {
+            Some(v) if v.is_acquisition_squitter() => self.acquisition_squitter = true,
+            Some(v) => {
+                self.interrogators.insert(v, self.timestamp);
+            }
+            None => {}
+        }
+    }
+
+    /// The advisory when its ARA, RAC, termination or multiple threat state differs from
+    /// the last one taken, once per change.
+    pub fn take_advisory_change(&mut self, ra: ResolutionAdvisory) -> Option<ResolutionAdvisory> {
+        let state = |ra: &ResolutionAdvisory| (ra.ara, ra.rac, ra.terminated, ra.multiple_threats);
+        match self.last_advisory.replace(ra) {
+            Some(last) if state(&last) == state(&ra) => None,
+            _ => Some(ra),
+        }
+    }
+
+    /// The plane squitters but has sent no ADS-B extended squitter, a Mode S-only transponder.
+    pub fn is_acquisition_only(&self) -> bool {
+        self.acquisition_squitter && !self.extended_squitter
+    }
+
+    /// Merges the ADS-B aircraft operational status (TC 31) into the plane.
+    pub(crate) fn update_operational_status(&mut self, operational_status: &OperationalStatus) {
+        if operational_status.nacp.is_some() {
+            self.nacp = operational_status.nacp;
+        }
+        if operational_status.sil.is_some() {
+            self.sil = operational_status.sil;
+        }
+        self.operational_status = Some(*operational_status);
+    }
+
+    /// Derives the integrity of the position from the type code of the position message.
+    ///
+    /// Version 0 reports NUCp, mapped to the NIC of the closest containment radius.
+    /// Versions 1 and 2 take the NIC supplements of the last operational status and
+    /// the NIC supplement B of the airborne position message.
+    pub(crate) fn update_integrity(&mut self, message_type: u32, nic_supplement_b: Option<u32>) {
+        match self.adsb_version.unwrap_or(0) {
+            0 => {
+                self.nuc_p = decoder::nuc_p(message_type);
+                self.nic = self.nuc_p.map(decoder::nic_from_nuc);
+            }
+            version => {
+                let status = self.operational_status.unwrap_or_default();
+                let supplements = (
+                    status.nic_supplement_a.unwrap_or(0),
+                    nic_supplement_b.unwrap_or(0),
+                    status.nic_supplement_c.unwrap_or(0),
+                );
+                self.nuc_p = None;
+                self.nic = decoder::nic(message_type, version, supplements);
+            }
+        }
+    }
+}
+
+pub(crate) fn target_altitude_source_char(source: Option<u32>) -> char {
+    match source {
+        Some(1) => '\u{2081}',
+        Some(2) => '\u{2082}',
+        Some(3) => '\u{2083}',
+        _ => ' ',
+    }
 }
 
 impl Default for Plane {
@@ -141,7 +366,11 @@ impl Default for Plane {
 impl Display for Plane {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "ICAO: {:06X}", self.icao)?;
-        write!(f, " Reg: {:2}", self.reg)?;
+        write!(
+            f,
+            " Reg: {:7}",
+            self.registration.as_deref().unwrap_or(self.reg)
+        )?;
         if let Some(altitude) = self.altitude {
             write!(f, " Alt: {:>5}", altitude)?;
         } else {
@@ -175,3 +404,23 @@ impl Display for Plane {
         write!(f, "")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::message;
+
+    #[test]
+    fn test_take_advisory_change() {
+        let advisory = |squitter: &str| {
+            message(squitter).and_then(|message| ResolutionAdvisory::from_message(&message))
+        };
+        let first = advisory("80E19718308C00052C6B4480A626").unwrap();
+        let second = advisory("80E1971830E200052C6B44FBE187").unwrap();
+        let mut plane = Plane::new();
+        assert_eq!(plane.take_advisory_change(first), Some(first));
+        assert_eq!(plane.take_advisory_change(first), None);
+        assert_eq!(plane.take_advisory_change(second), Some(second));
+        assert_eq!(plane.take_advisory_change(first), Some(first));
+    }
+}
@@ -0,0 +1,153 @@
+use super::{update_position::haversine, Plane};
+use crate::decoder;
+use log::warn;
+use std::fmt::{self, Display};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Highest speed in knots a fix may imply since the previous one.
+const MAX_AIRBORNE_SPEED: f64 = 1000.0;
+const MAX_SURFACE_SPEED: f64 = 250.0;
+
+/// Distance in kilometers allowed on top of the speed, covers the CPR resolution
+/// and frames received within the same second.
+const DISTANCE_MARGIN: f64 = 1.0;
+
+/// Consecutive speed rejects after which the previous fix is taken as the wrong one.
+const MAX_SPEED_REJECTS: u32 = 3;
+
+/// Reason of rejecting a decoded position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PositionReject {
+    /// The even and odd frames of the pair decode into different NL zones.
+    ZoneStraddle,
+    /// The fix implies an impossible speed since the previous fix.
+    Speed,
+    /// The fix is beyond the maximum receiver range from the observer.
+    Range,
+}
+
+impl PositionReject {
+    const ALL: [PositionReject; 3] = [
+        PositionReject::ZoneStraddle,
+        PositionReject::Speed,
+        PositionReject::Range,
+    ];
+}
+
+impl Display for PositionReject {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let symbol = match self {
+            PositionReject::ZoneStraddle => "NLZ",
+            PositionReject::Speed => "SPD",
+            PositionReject::Range => "RNG",
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+static POSITION_REJECTS: [AtomicU64; 3] = [AtomicU64::new(0), AtomicU64::new(0), AtomicU64::new(0)];
+
+/// Number of positions rejected for each reason since the start.
+pub fn position_rejects() -> Vec<(PositionReject, u64)> {
+    PositionReject::ALL
+        .iter()
+        .map(|&reject| {
+            (
+                reject,
+                POSITION_REJECTS[reject as usize].load(Ordering::Relaxed),
+            )
+        })
+        .collect()
+}
+
+impl Plane {
+    /// Checks the decoded fix against the previous fix of the plane and the receiver range.
+    pub(super) fn plausible_position(
+        &mut self,
+        lat: f64,
+        lon: f64,
+        surface: bool,
+        observer: Option<(f64, f64)>,
+    ) -> Result<(), PositionReject> {
+        if let (Some(max_range), Some(observer)) = (decoder::observer::get_max_range(), observer) {
+            if haversine(lat, lon, observer.0, observer.1) > max_range {
+                return Err(PositionReject::Range);
+            }
+        }
+        // the speed can't be checked when the time base of the receiver is reset
+        if let Some(position_timestamp) = self
+            .position_timestamp
+            .filter(|position_timestamp| self.timestamp >= *position_timestamp)
+        {
+            let elapsed = self
+                .timestamp
+                .signed_duration_since(position_timestamp)
+                .num_milliseconds() as f64
+                / 1000.0;
+            let max_speed = match surface {
+                true => MAX_SURFACE_SPEED,
+                false => MAX_AIRBORNE_SPEED,
+            };
+            let max_distance = max_speed * 1.852 * elapsed / 3600.0 + DISTANCE_MARGIN;
+            if haversine(lat, lon, self.lat, self.lon) > max_distance {
+                self.consecutive_speed_rejects += 1;
+                if self.consecutive_speed_rejects >= MAX_SPEED_REJECTS {
+                    // the previous fix was the corrupt one, start over from the next pair
+                    self.position_timestamp = None;
+                    self.consecutive_speed_rejects = 0;
+                }
+                return Err(PositionReject::Speed);
+            }
+        }
+        self.consecutive_speed_rejects = 0;
+        Ok(())
+    }
+
+    /// Counts and logs the rejected position.
+    pub(super) fn reject_position(&self, reject: PositionReject, position: Option<(f64, f64)>) {
+        POSITION_REJECTS[reject as usize].fetch_add(1, Ordering::Relaxed);
+        match position {
+            Some((lat, lon)) => warn!(
+                "ICAO:{:06X} TC:{} position {:.5},{:.5} rejected: {}",
+                self.icao, self.last_type_code, lat, lon, reject
+            ),
+            None => warn!(
+                "ICAO:{:06X} TC:{} position rejected: {}",
+                self.icao, self.last_type_code, reject
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+
+    #[test]
+    fn test_plausible_position() {
+        let mut plane = Plane::new();
+        plane.timestamp = Utc::now();
+        plane.position_timestamp = Some(plane.timestamp - Duration::seconds(10));
+        (plane.lat, plane.lon) = (52.0, -8.0);
+
+        // 10 seconds at 480 kt
+        assert_eq!(plane.plausible_position(52.02, -8.0, false, None), Ok(()));
+        // a kilometre a second is too fast on the ground
+        assert_eq!(
+            plane.plausible_position(52.09, -8.0, true, None),
+            Err(PositionReject::Speed)
+        );
+        assert_eq!(
+            plane.plausible_position(48.0, 2.0, false, None),
+            Err(PositionReject::Speed)
+        );
+        assert_eq!(plane.consecutive_speed_rejects, 2);
+        assert_eq!(
+            plane.plausible_position(48.0, 2.0, false, None),
+            Err(PositionReject::Speed)
+        );
+        assert_eq!(plane.position_timestamp, None);
+        assert_eq!(plane.plausible_position(48.0, 2.0, false, None), Ok(()));
+    }
+}
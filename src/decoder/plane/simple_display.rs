@@ -1,17 +1,47 @@
 use super::Plane;
 use crate::decoder;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use std::fmt;
 
+/// Optional column groups of the plane table.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DisplayFlags {
+    pub weather: bool,
+    pub angles: bool,
+    pub speed: bool,
+    pub altitude: bool,
+    pub extra: bool,
+    pub integrity: bool,
+    /// MCP and FMS selected altitudes, selected heading, autopilot modes and level bust.
+    pub autopilot: bool,
+    /// Lists the aircraft by interrogator and the acquisition-only aircraft below the table.
+    pub radars: bool,
+    /// Hides the positions with a lower (or unknown) NIC.
+    pub min_nic: Option<u32>,
+}
+
+impl DisplayFlags {
+    pub fn from_flags(flags: &[char]) -> Self {
+        DisplayFlags {
+            weather: flags.contains(&'w'),
+            angles: flags.contains(&'a'),
+            speed: flags.contains(&'s'),
+            altitude: flags.contains(&'A'),
+            extra: flags.contains(&'e'),
+            integrity: flags.contains(&'i'),
+            autopilot: flags.contains(&'p'),
+            radars: flags.contains(&'r'),
+            min_nic: None,
+        }
+    }
+}
+
 pub trait SimpleDisplay {
     fn simple_display(
         &self,
         f: &mut fmt::Formatter,
-        weather: bool,
-        angles: bool,
-        speed: bool,
-        altitude: bool,
-        extra: bool,
+        flags: &DisplayFlags,
+        now: DateTime<Utc>,
     ) -> fmt::Result;
 }
 
@@ -19,14 +49,24 @@ impl SimpleDisplay for Plane {
     fn simple_display(
         &self,
         f: &mut fmt::Formatter,
-        weather: bool,
-        angles: bool,
-        speed: bool,
-        altitude: bool,
-        extra: bool,
+        flags: &DisplayFlags,
+        now: DateTime<Utc>,
     ) -> fmt::Result {
-        write!(f, "{:06X} ", self.icao)?;
-        write!(f, "{:2} ", self.reg)?;
+        let DisplayFlags {
+            weather,
+            angles,
+            speed,
+            altitude,
+            extra,
+            integrity,
+            autopilot,
+            min_nic,
+            ..
+        } = *flags;
+        let position_shown =
+            min_nic.is_none_or(|min_nic| self.nic.is_some_and(|nic| nic >= min_nic));
+        write!(f, "{:06X}{}", self.icao, self.address_type)?;
+        write!(f, "{:7} ", self.registration.as_deref().unwrap_or(self.reg))?;
         if let Some(squawk) = self.squawk {
             write!(f, "{:04}", squawk)?;
         } else {
@@ -37,6 +77,19 @@ impl SimpleDisplay for Plane {
         } else {
             write!(f, " ")?;
         }
+        if let Some(emergency) = self
+            .emergency
+            .or(self.squawk.and_then(decoder::Emergency::from_squawk))
+        {
+            write!(f, "{}", emergency)?;
+        } else {
+            write!(f, " ")?;
+        }
+        match (self.spi, self.alert) {
+            (true, _) => write!(f, "I ")?,
+            (false, true) => write!(f, "A ")?,
+            _ => write!(f, "  ")?,
+        }
         if let Some(w) = decoder::icao_wtc(&self.category) {
             write!(f, "{} ", w)?;
         } else {
@@ -47,12 +100,16 @@ impl SimpleDisplay for Plane {
         } else {
             write!(f, "{:8} ", "")?;
         }
-        if self.lat != 0.0 && self.lon != 0.0 {
+        if self.lat != 0.0 && self.lon != 0.0 && position_shown {
             write!(f, "{:9.5} {:11.5} ", self.lat, self.lon)?;
         } else {
             write!(f, "{:9} {:11} ", "", "")?;
         }
-        if let Some(distance_from_observer) = &self.distance_from_observer {
+        if let Some(distance_from_observer) = self
+            .distance_from_observer
+            .as_ref()
+            .filter(|_| position_shown)
+        {
             write!(f, "{:5.1} ", distance_from_observer)?;
         } else {
             write!(f, "{:5} ", "")?;
@@ -159,6 +216,66 @@ impl SimpleDisplay for Plane {
             } else {
                 write!(f, "{:2} ", "")?;
             }
+            if let Some(hazard) = &self.hazard {
+                for level in hazard.levels() {
+                    match level {
+                        Some(level) => write!(f, "{}", level as u32)?,
+                        None => write!(f, "-")?,
+                    }
+                }
+                write!(f, " ")?;
+                match hazard.radio_height {
+                    Some(radio_height) => write!(f, "{:>5} ", radio_height)?,
+                    None => write!(f, "{:5} ", "")?,
+                }
+            } else {
+                write!(f, "{:5} {:5} ", "", "")?;
+            }
+        }
+        if integrity {
+            if let Some(nic) = self.nic {
+                let source = if self.nuc_p.is_some() { 'u' } else { ' ' };
+                write!(f, "{:>2}{} ", nic, source)?;
+            } else {
+                write!(f, "{:3} ", "")?;
+            }
+            if let Some(nacp) = self.nacp {
+                write!(f, "{:>2} ", nacp)?;
+            } else {
+                write!(f, "{:2} ", "")?;
+            }
+            if let Some(nacv) = self.nacv {
+                write!(f, "{:1} ", nacv)?;
+            } else {
+                write!(f, "{:1} ", "")?;
+            }
+            if let Some(sil) = self.sil {
+                write!(f, "{:1} ", sil)?;
+            } else {
+                write!(f, "{:1} ", "")?;
+            }
+        }
+        if autopilot {
+            if let Some(value) = self.mcp_selected_altitude {
+                write!(f, "{:>5} ", value)?;
+            } else {
+                write!(f, "{:5} ", "")?;
+            }
+            if let Some(value) = self.fms_selected_altitude {
+                write!(f, "{:>5} ", value)?;
+            } else {
+                write!(f, "{:5} ", "")?;
+            }
+            if let Some(value) = self.selected_heading {
+                write!(f, "{:>3} ", value)?;
+            } else {
+                write!(f, "{:3} ", "")?;
+            }
+            write!(f, "{} ", self.autopilot_state())?;
+            match self.is_level_bust() {
+                true => write!(f, "! ")?,
+                false => write!(f, "  ")?,
+            }
         }
         if extra {
             write!(f, "{}{} ", self.category.0, self.category.1)?;
@@ -177,16 +294,22 @@ impl SimpleDisplay for Plane {
             } else {
                 write!(f, "{:1} ", "")?;
             }
+            if let Some(data_link_capability) = self.data_link_capability {
+                write!(f, "{:1} ", data_link_capability.transponder_level())?;
+            } else {
+                write!(f, "{:1} ", "")?;
+            }
             write!(f, "{} ", self.surveillance_status)?;
+            if let Some(signal) = self.signal {
+                write!(f, "{:>3} ", signal)?;
+            } else {
+                write!(f, "{:3} ", "")?;
+            }
             if let Some(position_timestamp) = self.position_timestamp {
                 write!(
                     f,
                     "{:X}",
-                    Utc::now()
-                        .signed_duration_since(position_timestamp)
-                        .num_seconds()
-                        / 10
-                        % 16
+                    now.signed_duration_since(position_timestamp).num_seconds() / 10 % 16
                 )?;
             } else {
                 write!(f, " ")?;
@@ -195,11 +318,7 @@ impl SimpleDisplay for Plane {
                 write!(
                     f,
                     "{:X}",
-                    Utc::now()
-                        .signed_duration_since(track_timestamp)
-                        .num_seconds()
-                        / 10
-                        % 16
+                    now.signed_duration_since(track_timestamp).num_seconds() / 10 % 16
                 )?;
             } else {
                 write!(f, " ")?;
@@ -208,11 +327,7 @@ impl SimpleDisplay for Plane {
                 write!(
                     f,
                     "{:X} ",
-                    Utc::now()
-                        .signed_duration_since(heading_timestamp)
-                        .num_seconds()
-                        / 10
-                        % 16
+                    now.signed_duration_since(heading_timestamp).num_seconds() / 10 % 16
                 )?;
             } else {
                 write!(f, "  ")?;
@@ -221,32 +336,23 @@ impl SimpleDisplay for Plane {
         write!(
             f,
             "{:>2}",
-            Utc::now()
-                .signed_duration_since(self.timestamp)
-                .num_seconds()
+            now.signed_duration_since(self.timestamp).num_seconds()
         )
     }
 }
 
-pub struct SimpleDisplayWrapper<'a, T: SimpleDisplay>(&'a T, bool, bool, bool, bool, bool);
+pub struct SimpleDisplayWrapper<'a, T: SimpleDisplay>(&'a T, DisplayFlags, DateTime<Utc>);
 
 impl<'a, T: SimpleDisplay> fmt::Display for SimpleDisplayWrapper<'a, T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.0
-            .simple_display(f, self.1, self.2, self.3, self.4, self.5)
+        self.0.simple_display(f, &self.1, self.2)
     }
 }
 
 pub fn format_simple_display<T: SimpleDisplay>(
     item: &T,
-    weather: bool,
-    angles: bool,
-    speed: bool,
-    altitude: bool,
-    extra: bool,
+    flags: &DisplayFlags,
+    now: DateTime<Utc>,
 ) -> String {
-    format!(
-        "{}",
-        SimpleDisplayWrapper(item, weather, angles, speed, altitude, extra)
-    )
+    format!("{}", SimpleDisplayWrapper(item, *flags, now))
 }
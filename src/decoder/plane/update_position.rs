@@ -1,7 +1,10 @@
-use super::Plane;
+use super::{Plane, PositionReject};
 use crate::decoder;
 use std::f64::consts::PI;
 
+/// Reference positions older than this are not used for the local CPR decoding.
+const LOCAL_REFERENCE_AGE: i64 = 30;
+
 /// Updates the position of the plane based on the received message type and CPR format.
 ///
 /// # Arguments
@@ -11,15 +14,80 @@ use std::f64::consts::PI;
 ///
 /// # Remarks
 ///
-/// This method updates the position of the plane if the following conditions are met:
-/// - The CPR latitude and longitude values are not equal to zero.
-/// - The time difference between the CPR timestamps is less than 10 seconds.
+/// Every even and odd frame pair, both non-zero and received within 10 seconds of each
+/// other, is decoded globally, so the first fix and every fix with a pair at hand comes
+/// from the unambiguous decoding. Surface pairs are ambiguous by 90 degrees and take the
+/// quadrant closest to the last position of the plane or the observer.
+///
+/// A single frame without a pair is decoded locally against the last position of the
+/// plane, if it is recent. The local decoding can't tell the zone of the frame and
+/// resolves it within half a zone (180 NM, 45 NM on the surface) of the reference, so
+/// the observer position is never used as the reference of an unconfirmed first fix.
+/// A wrong track is caught by the speed check against the next global fix. A pair
+/// straddling an NL zone boundary is rejected and the frame decoded locally instead.
 ///
-/// If the conditions are met and the calculated latitude and longitude values are within the valid range,
-/// the plane's latitude, longitude, and position timestamp are updated.
+/// If the calculated latitude and longitude values are within the valid range and pass
+/// the plausibility checks, the plane's latitude, longitude, and position timestamp are
+/// updated. Rejected fixes are counted and logged with the reason.
 ///
 impl Plane {
     pub(super) fn update_position(&mut self, message_type: u32, cpr_form: u32) {
+        let coeff = match message_type {
+            5..=8 => 4,
+            9..=18 => 1,
+            _ => return,
+        };
+        let observer = decoder::observer::get_observer_coords();
+        let position = match self.global_position(cpr_form, coeff, observer) {
+            Ok(Some(position)) => Some(position),
+            Ok(None) => self
+                .reference_position()
+                .and_then(|reference| self.local_position(cpr_form, reference, coeff)),
+            Err(reject) => {
+                self.reject_position(reject, None);
+                self.reference_position()
+                    .and_then(|reference| self.local_position(cpr_form, reference, coeff))
+            }
+        };
+        if let Some((lat, lon)) = position {
+            if (-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon) {
+                if let Err(reject) = self.plausible_position(lat, lon, coeff == 4, observer) {
+                    self.reject_position(reject, Some((lat, lon)));
+                    return;
+                }
+                self.lat = lat;
+                self.lon = lon;
+                if let Some(observer) = observer {
+                    self.distance_from_observer =
+                        Some(haversine(self.lat, self.lon, observer.0, observer.1));
+                };
+                self.position_timestamp = Some(self.timestamp);
+            }
+        }
+    }
+
+    /// The last position of the plane, if recent enough to decode the next frame against.
+    fn reference_position(&self) -> Option<(f64, f64)> {
+        self.position_timestamp
+            .filter(|position_timestamp| {
+                self.timestamp
+                    .signed_duration_since(*position_timestamp)
+                    .num_seconds()
+                    < LOCAL_REFERENCE_AGE
+            })
+            .filter(|_| self.lat != 0.0 && self.lon != 0.0)
+            .map(|_| (self.lat, self.lon))
+    }
+
+    /// Surface pairs need a reference for the quadrant, the last fix of the plane of any age
+    /// or the observer position. A pair which does not decode into the same NL zone is
+    /// rejected.
+    fn global_position(
+        &self,
+        cpr_form: u32,
+        coeff: i32,
+        observer: Option<(f64, f64)>,
+    ) -> Result<Option<(f64, f64)>, PositionReject> {
         if self.cpr_lat[0] != 0
             && self.cpr_lat[1] != 0
             && self.cpr_lon[0] != 0
@@ -30,23 +98,40 @@ impl Plane {
                 .abs()
                 < 10
         {
-            if let Some((lat, lon)) = match message_type {
-                5..=8 => decoder::cpr_location(&self.cpr_lat, &self.cpr_lon, cpr_form, 4),
-                9..=18 => decoder::cpr_location(&self.cpr_lat, &self.cpr_lon, cpr_form, 1),
-                _ => None,
-            } {
-                if (-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon) {
-                    self.lat = lat;
-                    self.lon = lon;
-                    if let Some(observer) = decoder::observer::get_observer_coords() {
-                        self.distance_from_observer =
-                            Some(haversine(self.lat, self.lon, observer.0, observer.1));
+            let position = match coeff {
+                1 => decoder::cpr_location(&self.cpr_lat, &self.cpr_lon, cpr_form),
+                _ => {
+                    let reference = match self.lat != 0.0 && self.lon != 0.0 {
+                        true => Some((self.lat, self.lon)),
+                        false => observer,
                     };
-                    self.position_timestamp = Some(self.timestamp);
+                    let Some(reference) = reference else {
+                        return Ok(None);
+                    };
+                    decoder::cpr_location_surface(&self.cpr_lat, &self.cpr_lon, cpr_form, reference)
                 }
-            }
+            };
+            position.map(Some).ok_or(PositionReject::ZoneStraddle)
+        } else {
+            Ok(None)
         }
     }
+
+    fn local_position(
+        &self,
+        cpr_form: u32,
+        reference: (f64, f64),
+        coeff: i32,
+    ) -> Option<(f64, f64)> {
+        let form = cpr_form as usize;
+        decoder::cpr_location_local(
+            self.cpr_lat[form],
+            self.cpr_lon[form],
+            cpr_form,
+            reference,
+            coeff,
+        )
+    }
 }
 
 fn degrees_to_radians(degrees: f64) -> f64 {
@@ -70,3 +155,56 @@ pub(super) fn haversine(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
 
     r * c
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+
+    // 8D40621D58C382D690C8AC2863A7 even, 8D40621D58C386435CC412692AD6 odd
+    fn plane_with_even_frame() -> Plane {
+        let mut plane = Plane::new();
+        plane.timestamp = Utc::now();
+        (plane.cpr_lat[0], plane.cpr_lon[0]) = (93000, 51372);
+        plane.cpr_time[0] = plane.timestamp;
+        plane.update_position(11, 0);
+        plane
+    }
+
+    fn add_odd_frame(plane: &mut Plane) {
+        plane.timestamp += Duration::seconds(1);
+        (plane.cpr_lat[1], plane.cpr_lon[1]) = (74158, 50194);
+        plane.cpr_time[1] = plane.timestamp;
+        plane.update_position(11, 1);
+    }
+
+    #[test]
+    fn test_first_fix_from_pair() {
+        let mut plane = plane_with_even_frame();
+        assert_eq!(plane.position_timestamp, None);
+        add_odd_frame(&mut plane);
+        assert_eq!(plane.position_timestamp, Some(plane.timestamp));
+        assert!((plane.lat - 52.26578).abs() < 1e-4, "{}", plane.lat);
+        assert!((plane.lon - 3.93891).abs() < 1e-4, "{}", plane.lon);
+
+        // a single frame decoded locally against the fix
+        plane.timestamp += Duration::seconds(11);
+        plane.cpr_time[0] = plane.timestamp;
+        plane.update_position(11, 0);
+        assert_eq!(plane.position_timestamp, Some(plane.timestamp));
+        assert!((plane.lat - 52.25720).abs() < 1e-4, "{}", plane.lat);
+    }
+
+    #[test]
+    fn test_wrong_track_replaced_by_pair() {
+        let mut plane = plane_with_even_frame();
+        // a fix one latitude zone north, the local decoding would stay in its zone
+        (plane.lat, plane.lon) = (58.26578, 3.93891);
+        plane.position_timestamp = Some(plane.timestamp);
+        // the speed check drops the wrong fix after three rejects, the next pair is taken
+        for _ in 0..4 {
+            add_odd_frame(&mut plane);
+        }
+        assert!((plane.lat - 52.26578).abs() < 1e-4, "{}", plane.lat);
+    }
+}
@@ -59,7 +59,7 @@ pub(crate) fn status_flag_and_range_value(
     flag_and_range_value(message, flag, sb, eb).map(|(f, v)| (status, f, v))
 }
 
-fn bit_location(position: u32) -> (usize, usize) {
+pub(crate) fn bit_location(position: u32) -> (usize, usize) {
     let ibyte: usize = ((position - 1) / 4).try_into().unwrap();
     let ibit: usize = ((position - 1) % 4).try_into().unwrap();
     (ibyte, ibit)
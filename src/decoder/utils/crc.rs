@@ -1,8 +1,14 @@
+use std::collections::HashMap;
 use std::vec;
 
+use lazy_static::lazy_static;
 use log::debug;
 
-use crate::decoder::range_value;
+use crate::decoder::{bit_location, range_value};
+
+lazy_static! {
+    static ref SYNDROMES: HashMap<u32, Vec<u32>> = syndrome_table();
+}
 
 pub(crate) fn get_crc(message: &[u32], df: u32) -> u32 {
     match df {
@@ -51,6 +57,80 @@ fn crc56(message: &[u32]) -> u32 {
     data >> 8
 }
 
+/// Calculates the CRC syndrome of the message, the parity field XORed with the computed CRC.
+///
+/// The syndrome is zero for an error free DF11/DF17/DF18 frame.
+pub(crate) fn syndrome(message: &[u32]) -> u32 {
+    let len = (message.len() * 4) as u32;
+    let parity = range_value(message, len - 23, len).unwrap_or(0);
+    match len {
+        56 => parity ^ crc56(message),
+        _ => parity ^ crc112(message),
+    }
+}
+
+/// Repairs bit errors of a 112 bits message using the syndrome table.
+///
+/// # Arguments
+///
+/// * `message` - The message to repair.
+/// * `level` - The maximum number of bits to repair.
+///
+/// # Returns
+///
+/// The number of repaired bits, or `None` if the message can't be repaired.
+pub(crate) fn correct_errors(message: &mut [u32], level: u32) -> Option<u32> {
+    match syndrome(message) {
+        0 => Some(0),
+        _ if message.len() != 28 => None,
+        s => match SYNDROMES.get(&s) {
+            Some(bits) if bits.len() as u32 <= level => {
+                bits.iter().for_each(|&bit| flip_bit(message, bit));
+                Some(bits.len() as u32)
+            }
+            _ => None,
+        },
+    }
+}
+
+fn flip_bit(message: &mut [u32], position: u32) {
+    let (ibyte, ibit) = bit_location(position);
+    message[ibyte] ^= 8 >> ibit;
+}
+
+/// Builds the syndromes of all single and two bits errors of a 112 bits message.
+/// The DF field (bits 1-5) is never repaired, ambiguous syndromes are left out.
+fn syndrome_table() -> HashMap<u32, Vec<u32>> {
+    let syndrome_of = |bits: &[u32]| {
+        let mut message = [0u32; 28];
+        bits.iter().for_each(|&bit| flip_bit(&mut message, bit));
+        syndrome(&message)
+    };
+
+    let mut table: HashMap<u32, Vec<u32>> = HashMap::new();
+    for i in 6..=112 {
+        table.insert(syndrome_of(&[i]), vec![i]);
+    }
+    let mut ambiguous = vec![];
+    for i in 6..=112 {
+        for j in i + 1..=112 {
+            let s = syndrome_of(&[i, j]);
+            match table.get(&s) {
+                Some(bits) if bits.len() == 1 => {}
+                Some(_) => ambiguous.push(s),
+                None => {
+                    table.insert(s, vec![i, j]);
+                }
+            }
+        }
+    }
+    ambiguous.iter().for_each(|s| {
+        table.remove(s);
+    });
+    debug!("Syndromes: {}, ambiguous: {}", table.len(), ambiguous.len());
+    table
+}
+
 /// Calculate the reminder of the message
 ///
 /// # Arguments
@@ -136,6 +216,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_syndrome() {
+        let squitters = [
+            ("8D40621D58C382D690C8AC2863A7", 0),
+            ("8F4CA13D9910A430480410D74696", 4096),
+            ("02E197B00179C3", 0x4B18FE),
+        ];
+
+        for (squitter, value) in squitters.iter() {
+            let message = squitter
+                .chars()
+                .map(|c| c.to_digit(16).unwrap())
+                .collect::<Vec<u32>>();
+            assert_eq!(syndrome(&message), *value, "{}", squitter);
+        }
+    }
+
+    #[test]
+    fn test_correct_errors() {
+        let valid = message("8D40621D58C382D690C8AC2863A7").unwrap();
+        for bits in [
+            vec![],
+            vec![6],
+            vec![40],
+            vec![112],
+            vec![9, 77],
+            vec![88, 89],
+        ] {
+            let mut corrupted = valid.clone();
+            bits.iter().for_each(|&bit| flip_bit(&mut corrupted, bit));
+            assert_eq!(
+                correct_errors(&mut corrupted, 2),
+                Some(bits.len() as u32),
+                "{:?}",
+                bits
+            );
+            assert_eq!(corrupted, valid, "{:?}", bits);
+        }
+    }
+
+    #[test]
+    fn test_correct_errors_level() {
+        let valid = message("8D40621D58C382D690C8AC2863A7").unwrap();
+        let mut corrupted = valid.clone();
+        flip_bit(&mut corrupted, 9);
+        flip_bit(&mut corrupted, 77);
+        assert_eq!(correct_errors(&mut corrupted, 1), None);
+        let mut corrupted = valid.clone();
+        flip_bit(&mut corrupted, 3);
+        assert_eq!(correct_errors(&mut corrupted, 2), None);
+        assert_eq!(correct_errors(&mut valid.clone(), 0), Some(0));
+    }
+
     #[test]
     fn test_reminder() {
         let squitters = [
@@ -8,9 +8,9 @@ use log::{debug, warn};
 ///
 /// # Returns
 ///
-/// * `Option<String>` - A cleaned squitter
+/// * `Option<(Option<u64>, String)>` - The receiver timestamp, if the line has one, and a cleaned squitter
 ///
-pub(crate) fn clean_squitter(line: &str) -> Option<String> {
+pub(crate) fn clean_squitter(line: &str) -> Option<(Option<u64>, String)> {
     let ascii_line = line
         .as_bytes()
         .iter()
@@ -25,8 +25,11 @@ pub(crate) fn clean_squitter(line: &str) -> Option<String> {
     debug!("a_line: {}, len:{}", line, line.len());
     debug!("t_line: {}, len:{}", trimmed_line, trimmed_line.len());
     match trimmed_line.len() {
-        14 | 28 => Some(trimmed_line.to_string()),
-        26 | 40 => Some(trimmed_line[12..].to_string()),
+        14 | 28 => Some((None, trimmed_line.to_string())),
+        26 | 40 => Some((
+            u64::from_str_radix(&trimmed_line[..12], 16).ok(),
+            trimmed_line[12..].to_string(),
+        )),
         _ => {
             warn!("Invalid squitter: {}", line);
             None
@@ -43,7 +46,7 @@ mod tests {
         let line = "8D40621D58C382D690C8AC2863A7";
         // let format = None;
         if let Some(result) = clean_squitter(line) {
-            assert_eq!(result, "8D40621D58C382D690C8AC2863A7");
+            assert_eq!(result, (None, "8D40621D58C382D690C8AC2863A7".to_string()));
         }
     }
 
@@ -51,7 +54,13 @@ mod tests {
     fn test_sbs_squitter() {
         let line = "@05FFD0CF94E28D49329099115719707811B06CF5;";
         if let Some(result) = clean_squitter(line) {
-            assert_eq!(result, "8D49329099115719707811B06CF5");
+            assert_eq!(
+                result,
+                (
+                    Some(0x05FFD0CF94E2),
+                    "8D49329099115719707811B06CF5".to_string()
+                )
+            );
         }
     }
 }
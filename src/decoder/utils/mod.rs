@@ -10,6 +10,7 @@ pub(crate) use format::*;
 pub(crate) use ma_code::*;
 pub(crate) use me_code::*;
 
+use crate::decoder::Frame;
 use log::{debug, warn};
 
 /// Converts a squitter string into a vector of u32 values.
@@ -22,29 +23,96 @@ use log::{debug, warn};
 ///
 /// * `Option<Vec<u32>>` - An Option vector of u32 values representing the converted squitter string.
 pub fn message(squitter: &str) -> Option<Vec<u32>> {
+    frame(squitter, 0).map(|frame| frame.message)
+}
+
+/// Converts a squitter string into a frame, keeping the receiver timestamp of AVR/SBS lines.
+///
+/// # Arguments
+///
+/// * `squitter` - The squitter string to convert, e.g. `@05DCF1CBB44B8D4077D458BF0275905760B04D75;`.
+/// * `error_correction` - The number of bit errors (0, 1 or 2) repaired in DF17/DF18 frames.
+///
+/// # Returns
+///
+/// * `Option<Frame>` - An Option frame holding the message and the receiver timestamp, if any.
+pub fn frame(squitter: &str, error_correction: u32) -> Option<Frame> {
     match clean_squitter(squitter) {
-        Some(cleaned_squitter) => match cleaned_squitter.len() {
-            14 | 28 => {
-                let message = cleaned_squitter
+        Some((timestamp, cleaned_squitter)) => match cleaned_squitter.len() {
+            14 | 28 => checked_frame(
+                cleaned_squitter
                     .chars()
                     .map(|c| u32::from_str_radix(&c.to_string(), 16).unwrap())
-                    .collect::<Vec<u32>>();
-                debug!("Message: {:?}", message);
-                let r = reminder(&message);
-                match r {
-                    0 => Some(message),
-                    _ => {
-                        warn!("{}, R:{}", cleaned_squitter, r);
-                        None
-                    }
-                }
-            }
+                    .collect::<Vec<u32>>(),
+                error_correction,
+            )
+            .map(|frame| Frame { timestamp, ..frame }),
             _ => None,
         },
         _ => None,
     }
 }
 
+/// Converts the raw bytes of a binary frame into a frame.
+///
+/// # Arguments
+///
+/// * `bytes` - The 7 or 14 bytes of a short or long Mode S frame.
+/// * `error_correction` - The number of bit errors (0, 1 or 2) repaired in DF17/DF18 frames.
+///
+/// # Returns
+///
+/// * `Option<Frame>` - An Option frame holding the message, one u32 value per hexadecimal digit.
+pub fn frame_from_bytes(bytes: &[u8], error_correction: u32) -> Option<Frame> {
+    match bytes.len() {
+        7 | 14 => checked_frame(
+            bytes
+                .iter()
+                .flat_map(|&b| [(b >> 4) as u32, (b & 0xF) as u32])
+                .collect::<Vec<u32>>(),
+            error_correction,
+        ),
+        _ => None,
+    }
+}
+
+/// Checks the parity of the message, DF17/DF18 frames are repaired up to the
+/// error correction level and dropped when they can't be repaired.
+fn checked_frame(mut message: Vec<u32>, error_correction: u32) -> Option<Frame> {
+    debug!("Message: {:?}", message);
+    match range_value(&message, 1, 5) {
+        Some(17) | Some(18) => match correct_errors(&mut message, error_correction) {
+            Some(corrected_bits) => {
+                if corrected_bits > 0 {
+                    debug!(
+                        "{}, corrected bits:{}",
+                        hex_message(&message),
+                        corrected_bits
+                    );
+                }
+                Some(Frame {
+                    corrected_bits,
+                    ..Frame::new(message)
+                })
+            }
+            None => {
+                warn!("{}, S:{:06X}", hex_message(&message), syndrome(&message));
+                None
+            }
+        },
+        _ => {
+            let r = reminder(&message);
+            match r {
+                0 => Some(Frame::new(message)),
+                _ => {
+                    warn!("{}, R:{}", hex_message(&message), r);
+                    None
+                }
+            }
+        }
+    }
+}
+
 pub(crate) fn hex_message(message: &[u32]) -> String {
     message
         .iter()
@@ -67,7 +135,7 @@ pub(crate) fn message_type(message: &[u32]) -> (u32, u32) {
     ((message[8] << 1) | (message[9] >> 3), message[9] & 7)
 }
 
-/// Retrieves the IC (Interrogator Code) value from a message.
+/// Retrieves the IC (Interrogator Code) value from a DF11 message.
 ///
 /// # Arguments
 ///
@@ -75,10 +143,12 @@ pub(crate) fn message_type(message: &[u32]) -> (u32, u32) {
 ///
 /// # Returns
 ///
-/// The IC value.
-//pub(crate) fn ic(message: &[u32]) -> u32 {
-//    (message[2] << 1) | (message[3] >> 3) & 0b11111
-//}
+/// The 7 bits code overlaid on the parity, the code label (CL) followed by the
+/// interrogator code. `None` for codes above 79 and for SI 0 (CL 1 with IC 0), the reply
+/// is corrupted.
+pub(crate) fn ic(message: &[u32]) -> Option<u32> {
+    Some(syndrome(message)).filter(|&code| code < 80 && code != 16)
+}
 
 /// Retrieves the CA (Capability) value from a message.
 ///
@@ -93,6 +163,19 @@ pub(crate) fn ca(message: &[u32]) -> u32 {
     message[1] & 0b0111
 }
 
+/// Retrieves the CF (Control Field) value from a DF18 message.
+///
+/// # Arguments
+///
+/// * `message` - The message to extract the CF value from.
+///
+/// # Returns
+///
+/// The CF value.
+pub(crate) fn cf(message: &[u32]) -> u32 {
+    message[1] & 0b0111
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,6 +195,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_frame_timestamp() {
+        let squitter = "@05DCF1CCCDC28D4077D458BF0275905760B04D75;";
+        match frame(squitter, 1) {
+            Some(frame) => {
+                assert_eq!(frame.timestamp, Some(0x05DCF1CCCDC2));
+                assert_eq!(Some(frame.message), message("8D4077D458BF0275905760B04D75"));
+            }
+            None => panic!("Failed to convert squitter to frame"),
+        }
+    }
+
+    #[test]
+    fn test_frame_from_bytes() {
+        let bytes = [
+            0x8D, 0x40, 0x62, 0x1D, 0x58, 0xC3, 0x82, 0xD6, 0x90, 0xC8, 0xAC, 0x28, 0x63, 0xA7,
+        ];
+        assert_eq!(
+            frame_from_bytes(&bytes, 1).map(|frame| frame.message),
+            message("8D40621D58C382D690C8AC2863A7")
+        );
+        assert_eq!(frame_from_bytes(&bytes[..5], 1), None);
+    }
+
+    #[test]
+    fn test_frame_error_correction() {
+        match frame("8F4CA13D9910A430480410D74696", 1) {
+            Some(frame) => {
+                assert_eq!(frame.corrected_bits, 1);
+                assert_eq!(hex_message(&frame.message), "8F4CA13D9910A430480410D75696");
+            }
+            None => panic!("Failed to repair the squitter"),
+        }
+        assert_eq!(frame("8F4CA13D9910A430480410D74696", 0), None);
+        assert_eq!(frame("8D40621D58C382D690C8AC2863A8", 2), None);
+    }
+
     #[test]
     fn test_message_short() {
         let squitter = "02E197B00179C3";
@@ -121,13 +241,21 @@ mod tests {
         }
     }
 
-    //#[test]
-    //fn test_ic() {
-    //    let squitter = "8D40621D58C382D690C8AC2863A7";
-    //    if let Some(message) = message(squitter) {
-    //        assert_eq!(ic(&message), 8);
-    //    }
-    //}
+    #[test]
+    fn test_ic() {
+        if let Some(message) = message("5D4CA2D468FE2F") {
+            assert_eq!(ic(&message), Some(0));
+        }
+        if let Some(message) = message("5D4CA2D468FE3E") {
+            assert_eq!(ic(&message), Some(17));
+        }
+        if let Some(message) = message("5D4CA2D468FE7F") {
+            assert_eq!(ic(&message), None);
+        }
+        if let Some(message) = message("5D4CA2D468FE3F") {
+            assert_eq!(ic(&message), None);
+        }
+    }
 
     #[test]
     fn test_ca() {
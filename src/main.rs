@@ -1,8 +1,8 @@
 mod reader;
-use reader::read_lines;
-use squitterator::decoder::{self, Plane};
+use reader::{read_beast, read_lines};
+use squitterator::decoder::{self, AddressType, Plane};
 
-use crate::decoder::Coordinates;
+use crate::decoder::{Coordinates, ReplayClock, WallClock};
 use clap::Parser;
 use env_logger::{Builder, Env};
 use log::{error, info};
@@ -21,8 +21,18 @@ use std::time::Duration;
     author = "Anton Sidorov tonysidrock@gmail.com",
     about = "ADS-B squitter decoder"
 )]
-
 struct Args {
+    #[clap(short = 'B', long, help = "Read Mode-S Beast binary frames")]
+    beast: bool,
+
+    #[clap(
+        long,
+        default_value = "60",
+        value_parser = clap::value_parser!(i64).range(0..),
+        help = "Seconds a DF11/DF17/DF18 frame confirms the address of the address/parity replies\n0 - accept every address"
+    )]
+    confirm_ttl: i64,
+
     #[clap(short, long, help = "Count squitters by type")]
     count_df: bool,
 
@@ -30,13 +40,22 @@ struct Args {
         short,
         long,
         default_value = "aAews",
-        help = "Display plane patameters\na - angles, A - altitude, s - speed\ne - extra info, w - weather\nQ - quiet"
+        help = "Display plane patameters\na - angles, A - altitude, s - speed\ne - extra info, i - integrity, w - weather\np - autopilot, r - aircraft by interrogator, Q - quiet"
     )]
     display: Vec<String>,
 
     #[clap(short = 'D', long, default_value = None)]
     downlink_log: Option<String>,
 
+    #[clap(
+        short = 'E',
+        long,
+        default_value = "1",
+        value_parser = clap::value_parser!(u32).range(0..=2),
+        help = "Error correction of DF17/DF18 squitters\n0 - off, 1 - single bit, 2 - two bits"
+    )]
+    error_correction: u32,
+
     #[clap(short = 'l', long, default_value = "sq.errors.log")]
     error_log: String,
 
@@ -49,6 +68,12 @@ struct Args {
     #[clap(short='M', long, default_value = None)]
     log_messages: Option<Vec<u32>>,
 
+    #[clap(long, default_value = None, help = "Reject positions beyond the range from the observer, km")]
+    max_range: Option<f64>,
+
+    #[clap(long, default_value = None, help = "Hide positions below the Navigation Integrity Category")]
+    min_nic: Option<u32>,
+
     #[clap(
         short,
         long,
@@ -64,9 +89,23 @@ struct Args {
     )]
     observer_coord: Option<String>,
 
+    #[clap(long, default_value = None, help = "Log ACAS resolution advisories to the file")]
+    ra_log: Option<String>,
+
+    #[clap(long, default_value = None, help = "Log meteorological hazard reports to the file")]
+    hazard_log: Option<String>,
+
     #[clap(short = 'R', long, help = "Relaxed Capabilities check EHS")]
     relaxed: bool,
 
+    #[clap(
+        long,
+        default_value = "0",
+        value_parser = parse_replay,
+        help = "Replay speed of the source file\n1 - real time, 10 - ten times faster,\n0 - as fast as possible"
+    )]
+    replay: f64,
+
     #[clap(
         short,
         long,
@@ -91,13 +130,22 @@ struct Args {
     use_update_method: bool,
 }
 
+/// Replay speed, zero or positive.
+fn parse_replay(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(speed) if speed >= 0.0 && speed.is_finite() => Ok(speed),
+        Ok(_) => Err(String::from("the replay speed must be zero or positive")),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
 fn main() -> io::Result<()> {
     let args = Args::parse();
 
     let error_log_file = File::create(&args.error_log).expect("Unable to create log file");
     let error_log_file = Mutex::new(error_log_file);
 
-    let mut planes: HashMap<u32, Plane> = HashMap::new();
+    let mut planes: HashMap<(u32, AddressType), Plane> = HashMap::new();
 
     let coords = if let Some(coord_str) = &args.observer_coord {
         match coord_str.parse::<Coordinates>() {
@@ -112,6 +160,7 @@ fn main() -> io::Result<()> {
     };
 
     decoder::set_observer_coords(coords);
+    decoder::set_max_range(args.max_range);
 
     // Initialize the logger
     Builder::from_env(Env::default().default_filter_or("error"))
@@ -123,6 +172,7 @@ fn main() -> io::Result<()> {
 
     match !args.tcp.is_empty() {
         true => loop {
+            let mut clock = WallClock::new();
             let stream = match TcpStream::connect(&args.tcp) {
                 Ok(stream) => {
                     info!("Successfully connected to the server {}", &args.tcp);
@@ -134,7 +184,11 @@ fn main() -> io::Result<()> {
                 }
             };
             let reader = BufReader::new(stream);
-            if let Err(e) = read_lines(reader, &args, &mut planes) {
+            let result = match args.beast {
+                true => read_beast(reader, &args, &mut planes, &mut clock),
+                _ => read_lines(reader, &args, &mut planes, &mut clock),
+            };
+            if let Err(e) = result {
                 error!("Error during reading: {}", e);
                 sleep(Duration::from_secs(5));
                 continue;
@@ -143,7 +197,11 @@ fn main() -> io::Result<()> {
         _ => {
             let file = File::open(&args.source)?;
             let reader = BufReader::new(file);
-            read_lines(reader, &args, &mut planes)
+            let mut clock = ReplayClock::new(args.replay);
+            match args.beast {
+                true => read_beast(reader, &args, &mut planes, &mut clock),
+                _ => read_lines(reader, &args, &mut planes, &mut clock),
+            }
         }
     }
 }
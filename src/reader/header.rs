@@ -1,15 +1,23 @@
-pub(super) fn print_header(
-    weather: bool,
-    angles: bool,
-    speed: bool,
-    altitude: bool,
-    extra: bool,
-    header: bool,
-) {
+use squitterator::decoder::DisplayFlags;
+
+pub(super) fn print_header(flags: &DisplayFlags, header: bool) {
+    let DisplayFlags {
+        weather,
+        angles,
+        speed,
+        altitude,
+        extra,
+        integrity,
+        autopilot,
+        ..
+    } = *flags;
+
     let headers_1 = [
         ("ICAO", 6),
-        ("RG", 2),
+        ("REG", 7),
         ("SQWK", 4),
+        ("E", 1),
+        ("I", 1),
         ("W", 1),
         ("CALLSIGN", 8),
         ("LATITUDE", 9),
@@ -29,14 +37,22 @@ pub(super) fn print_header(
         ("HUM", 3),
         ("PRES", 4),
         ("TB", 2),
+        ("HAZRD", 5),
+        ("RHGT", 5),
     ];
 
+    let headers_integrity = [("NIC", 3), ("NP", 2), ("NV", 1), ("SL", 1)];
+
+    let headers_autopilot = [("MCP", 5), ("FMS", 5), ("SHD", 3), ("MODES", 5), ("B", 1)];
+
     let extra_headers = [
         ("VX", 2),
         ("DF", 2),
         ("TC", 2),
         ("V", 1),
+        ("L", 1),
         ("S", 1),
+        ("SIG", 3),
         ("PTH", 3),
     ];
 
@@ -80,6 +96,22 @@ pub(super) fn print_header(
         } else {
             Vec::new()
         })
+        .chain(if integrity {
+            headers_integrity
+                .iter()
+                .map(|&(header, width)| format!("{:>width$} ", header, width = width))
+                .collect()
+        } else {
+            Vec::new()
+        })
+        .chain(if autopilot {
+            headers_autopilot
+                .iter()
+                .map(|&(header, width)| format!("{:>width$} ", header, width = width))
+                .collect()
+        } else {
+            Vec::new()
+        })
         .chain(if extra {
             extra_headers
                 .iter()
@@ -131,6 +163,22 @@ pub(super) fn print_header(
         } else {
             Vec::new()
         })
+        .chain(if integrity {
+            headers_integrity
+                .iter()
+                .map(|&(_, width)| format!("{:-<width$} ", "", width = width))
+                .collect()
+        } else {
+            Vec::new()
+        })
+        .chain(if autopilot {
+            headers_autopilot
+                .iter()
+                .map(|&(_, width)| format!("{:-<width$} ", "", width = width))
+                .collect()
+        } else {
+            Vec::new()
+        })
         .chain(if extra {
             extra_headers
                 .iter()
@@ -1,7 +1,19 @@
-pub(super) fn print_legend(weather: bool, angles: bool, speed: bool, extra: bool) {
+use squitterator::decoder::DisplayFlags;
+
+pub(super) fn print_legend(flags: &DisplayFlags) {
+    let DisplayFlags {
+        weather,
+        angles,
+        speed,
+        extra,
+        integrity,
+        autopilot,
+        ..
+    } = *flags;
+
     let legend = [
-        ("ICAO", "ICAO Address"),
-        ("RG", "Registraton Country Code"),
+        ("ICAO", "ICAO Address, ~ non-ICAO, t/T TIS-B, r/R ADS-R"),
+        ("REG", "Registration, Country Code when unknown"),
         ("ALT B", "Altitude (Barometric)"),
         ("SQWK", "Squawk"),
         ("CALLSIGN", "Callsign"),
@@ -13,6 +25,11 @@ pub(super) fn print_legend(weather: bool, angles: bool, speed: bool, extra: bool
         ("VRATE", "Vertical Rate"),
         ("LC", "Last Contact"),
         ("W", "Wake Turbulence Category"),
+        (
+            "E",
+            "Emergency (General, Medical, Fuel, Comms, Unlawful, Downed)",
+        ),
+        ("I", "Ident (SPI), A - Alert, squawk changed"),
     ];
 
     let legend_speed = [
@@ -28,6 +45,29 @@ pub(super) fn print_legend(weather: bool, angles: bool, speed: bool, extra: bool
         ("HUM", "Humidity"),
         ("PRES", "Static pressure"),
         ("TB", "Turbulence"),
+        (
+            "HAZRD",
+            "Hazards (Turbulence, Wind Shear, Microburst, Icing, Wake Vortex), 0 Nil - 3 Severe",
+        ),
+        ("RHGT", "Radio Height"),
+    ];
+
+    let legend_integrity = [
+        ("NIC", "Navigation Integrity Category, u - from NUCp"),
+        ("NP", "Navigation Accuracy Category (Position)"),
+        ("NV", "Navigation Accuracy Category (Velocity)"),
+        ("SL", "Source Integrity Level"),
+    ];
+
+    let legend_autopilot = [
+        ("MCP", "MCP/FCU Selected Altitude"),
+        ("FMS", "FMS Selected Altitude"),
+        ("SHD", "Selected Heading"),
+        (
+            "MODES",
+            "Autopilot, VNAV, Altitude Hold, Approach, LNAV, - disengaged",
+        ),
+        ("B", "Level Bust, moving away from the selected altitude"),
     ];
 
     let legend_extra = [
@@ -35,7 +75,9 @@ pub(super) fn print_legend(weather: bool, angles: bool, speed: bool, extra: bool
         ("DF", "Downlink Format"),
         ("TC", "Type Code"),
         ("V", "ASD-B Version"),
+        ("L", "Transponder Level"),
         ("S", "Surveillance Status"),
+        ("SIG", "Signal Level"),
         ("PTH", "Position, Track, Heaging age"),
     ];
 
@@ -99,6 +141,38 @@ pub(super) fn print_legend(weather: bool, angles: bool, speed: bool, extra: bool
         } else {
             Vec::new()
         })
+        .chain(if integrity {
+            legend_integrity
+                .iter()
+                .map(|&(header, description)| {
+                    format!(
+                        "{:w0$}: {:w1$}\n",
+                        header,
+                        description,
+                        w0 = width.0,
+                        w1 = width.1
+                    )
+                })
+                .collect()
+        } else {
+            Vec::new()
+        })
+        .chain(if autopilot {
+            legend_autopilot
+                .iter()
+                .map(|&(header, description)| {
+                    format!(
+                        "{:w0$}: {:w1$}\n",
+                        header,
+                        description,
+                        w0 = width.0,
+                        w1 = width.1
+                    )
+                })
+                .collect()
+        } else {
+            Vec::new()
+        })
         .chain(if extra {
             legend_extra
                 .iter()
@@ -1,17 +1,23 @@
 mod header;
 mod legend;
 mod planes;
+mod radars;
 
 use header::print_header;
 use legend::print_legend;
 use planes::print_planes;
+use radars::print_radars;
 
 use crate::Args;
-use squitterator::decoder::{self, df, icao, Downlink};
-use squitterator::decoder::{message, Plane};
+use squitterator::decoder::{
+    self, address_type, address_type_known, df, icao, AddressType, BeastReader, ConfirmedAddresses,
+    Downlink, Frame,
+};
+use squitterator::decoder::{Clock, DisplayFlags, Plane};
 //use squitterator::;
 use decoder::UpdateFromDownlink;
 
+use chrono::{DateTime, Utc};
 use log::{debug, error, warn};
 use std::collections::{BTreeMap, HashMap};
 use std::fs::File;
@@ -21,141 +27,271 @@ use std::sync::Mutex;
 pub(super) fn read_lines<R: BufRead>(
     reader: R,
     args: &Args,
-    planes: &mut HashMap<u32, Plane>,
+    planes: &mut HashMap<(u32, AddressType), Plane>,
+    clock: &mut dyn Clock,
+) -> Result<()> {
+    let frames = reader.lines().filter_map(|line| match line {
+        Ok(squitter) => {
+            debug!("Squitter: {}", squitter);
+            decoder::frame(&squitter, args.error_correction).map(Ok)
+        }
+        Err(e) => {
+            warn!("Warn: {}", e);
+            None
+        }
+    });
+    read_frames(frames, args, planes, clock)
+}
+
+pub(super) fn read_beast<R: BufRead>(
+    reader: R,
+    args: &Args,
+    planes: &mut HashMap<(u32, AddressType), Plane>,
+    clock: &mut dyn Clock,
+) -> Result<()> {
+    read_frames(
+        BeastReader::new(reader, args.error_correction),
+        args,
+        planes,
+        clock,
+    )
+}
+
+fn read_frames<I: Iterator<Item = Result<Frame>>>(
+    frames: I,
+    args: &Args,
+    planes: &mut HashMap<(u32, AddressType), Plane>,
+    clock: &mut dyn Clock,
 ) -> Result<()> {
     let downlink_error_log_file = args
         .downlink_log
         .as_ref()
         .map(|f| Mutex::new(File::create(f).expect("Unable to create downlink log file")));
 
+    let ra_log_file = args
+        .ra_log
+        .as_ref()
+        .map(|f| Mutex::new(File::create(f).expect("Unable to create RA log file")));
+
+    let hazard_log_file = args
+        .hazard_log
+        .as_ref()
+        .map(|f| Mutex::new(File::create(f).expect("Unable to create hazard log file")));
+
     let display_flags = args.display.concat().chars().collect::<Vec<char>>();
+    let flags = DisplayFlags {
+        min_nic: args.min_nic,
+        ..DisplayFlags::from_flags(&display_flags)
+    };
 
     if !display_flags.contains(&'Q') {
         clear_screen();
-        print_legend(
-            display_flags.contains(&'w'),
-            display_flags.contains(&'a'),
-            display_flags.contains(&'s'),
-            display_flags.contains(&'e'),
-        );
+        print_legend(&flags);
     }
 
+    let mut confirmed = ConfirmedAddresses::new(args.confirm_ttl);
     let mut df_count = BTreeMap::new();
-    let mut timestamp = chrono::Utc::now() + chrono::Duration::seconds(args.update);
-    for line in reader.lines() {
-        match line {
-            Ok(squitter) => {
-                debug!("Squitter: {}", squitter);
-
-                if let Some(message) = message(&squitter) {
-                    let df = match df(&message) {
-                        Some(df) => df,
-                        None => {
-                            continue;
-                        }
-                    };
+    let mut corrected_count = BTreeMap::new();
+    let mut timestamp = clock.now() + chrono::Duration::seconds(args.update);
+    for frame in frames {
+        let frame = frame?;
+        let message = &frame.message;
+        let frame_time = clock.frame_time(&frame);
+
+        if frame.corrected_bits > 0 {
+            *corrected_count.entry(frame.corrected_bits).or_insert(0) += 1;
+        }
+        let df = match df(message) {
+            Some(df) => df,
+            None => {
+                continue;
+            }
+        };
+
+        confirmed.confirm(message, df, frame_time);
+
+        if let Some(m) = &args.log_messages {
+            if m.contains(&df) {
+                error!("DF:{}, L:{}", df, frame);
+            }
+        }
+
+        if let Some(only) = &args.filter {
+            if only.iter().all(|&x| x != df) {
+                continue;
+            }
+        }
+
+        if args.count_df {
+            *df_count.entry(df).or_insert(1) += 1;
+        }
+
+        if let (Some(icao), Some(address_type)) = (icao(message, df), address_type(message, df)) {
+            // TIS-B/ADS-R messages without the IMF belong to the target already seen as non-ICAO
+            let address_type = match address_type_known(message, df) {
+                false if planes.contains_key(&(icao, address_type.non_icao())) => {
+                    address_type.non_icao()
+                }
+                _ => address_type,
+            };
 
-                    if let Some(m) = &args.log_messages {
-                        if m.contains(&df) {
-                            error!("DF:{}, L:{}", df, squitter);
+            if !confirmed.is_confirmed(icao, df, frame_time) {
+                debug!("DF:{}, unconfirmed ICAO:{:06X}", df, icao);
+                continue;
+            }
+
+            if let Ok(downlink) = decoder::DF::from_message(message) {
+                planes
+                    .entry((icao, address_type))
+                    .and_modify(|p| {
+                        if !args.use_update_method {
+                            p.update_from_downlink(&downlink, args.relaxed, frame_time)
+                        } else {
+                            p.update(message, df, args.relaxed, frame_time)
                         }
-                    }
+                    })
+                    .or_insert_with(|| {
+                        Plane::from_downlink(&downlink, icao, args.relaxed, frame_time)
+                    })
+                    .update_reception(&frame);
+            }
 
-                    if let Some(only) = &args.filter {
-                        if only.iter().all(|&x| x != df) {
-                            continue;
+            if let Some(ref raf) = ra_log_file {
+                if let Some(ra) = decoder::resolution_advisory(message, df)
+                    .filter(|ra| ra.is_active() || ra.terminated)
+                {
+                    if let Some(plane) = planes.get_mut(&(icao, address_type)) {
+                        if let Some(ra) = plane.take_advisory_change(ra) {
+                            let mut raf = raf.lock().unwrap();
+                            writeln!(raf, "{}", ra_log_line(plane, &ra, df, frame_time))?;
                         }
                     }
+                }
+            }
 
-                    if args.count_df {
-                        *df_count.entry(df).or_insert(1) += 1;
+            if let Some(ref hlf) = hazard_log_file {
+                if let Some(plane) = planes.get_mut(&(icao, address_type)) {
+                    if let Some(hazard) = plane
+                        .take_hazard_report()
+                        .filter(|hazard| hazard.is_hazardous())
+                    {
+                        let mut hlf = hlf.lock().unwrap();
+                        writeln!(hlf, "{},{}", plane_log_line(plane, frame_time), hazard)?;
                     }
+                }
+            }
 
-                    if let Some(icao) = icao(&message, df) {
-                        if let Ok(downlink) = decoder::DF::from_message(&message) {
-                            planes
-                                .entry(icao)
-                                .and_modify(|p| {
-                                    if df < 20 && !&args.use_update_method {
-                                        p.update_from_downlink(&downlink)
-                                    } else {
-                                        p.update(&message, df, args.relaxed)
-                                    }
-                                })
-                                .or_insert(Plane::from_downlink(&downlink, icao));
-                        }
-
-                        if let Some(ref dlf) = downlink_error_log_file {
-                            if let Ok(downlink) = decoder::DF::from_message(&message) {
-                                let mut dlf = dlf.lock().unwrap();
-                                write!(dlf, "{}", downlink)?;
-                                debug!("Writing to {:?}", &dlf);
-                            }
-                        }
+            if let Some(ref dlf) = downlink_error_log_file {
+                if let Ok(downlink) = decoder::DF::from_message(message) {
+                    let mut dlf = dlf.lock().unwrap();
+                    write!(dlf, "{}", downlink)?;
+                    debug!("Writing to {:?}", &dlf);
+                }
+            }
 
-                        let now = chrono::Utc::now();
-                        if now.signed_duration_since(timestamp).num_seconds() > args.update {
-                            planes.retain(|_, plane| {
-                                let elapsed =
-                                    now.signed_duration_since(plane.timestamp).num_seconds();
-                                if elapsed < 60 {
-                                    true
-                                } else {
-                                    debug!("Plane {} has been removed from view", plane.icao);
-                                    false
-                                }
-                            });
-                            planes.shrink_to_fit();
-
-                            if !display_flags.contains(&'Q') {
-                                clear_screen();
-                                print_header(
-                                    display_flags.contains(&'w'),
-                                    display_flags.contains(&'a'),
-                                    display_flags.contains(&'s'),
-                                    display_flags.contains(&'A'),
-                                    display_flags.contains(&'e'),
-                                    true,
-                                );
-                                print_planes(
-                                    planes,
-                                    args,
-                                    display_flags.contains(&'w'),
-                                    display_flags.contains(&'a'),
-                                    display_flags.contains(&'s'),
-                                    display_flags.contains(&'A'),
-                                    display_flags.contains(&'e'),
-                                );
-                                print_header(
-                                    display_flags.contains(&'w'),
-                                    display_flags.contains(&'a'),
-                                    display_flags.contains(&'s'),
-                                    display_flags.contains(&'A'),
-                                    display_flags.contains(&'e'),
-                                    false,
-                                );
-
-                                if args.count_df {
-                                    let result =
-                                        df_count.iter().fold(String::new(), |acc, (df, count)| {
-                                            acc + &format!("DF{}:{} ", df, count)
-                                        });
-                                    println!("{}", result);
-                                }
-
-                                debug!("{}", planes[&icao]);
-                                timestamp = now;
-                            }
-                        }
+            let now = clock.now();
+            if now.signed_duration_since(timestamp).num_seconds() > args.update {
+                planes.retain(|_, plane| {
+                    let elapsed = now.signed_duration_since(plane.timestamp).num_seconds();
+                    if elapsed < 60 {
+                        true
+                    } else {
+                        debug!("Plane {} has been removed from view", plane.icao);
+                        false
                     }
-                };
+                });
+                planes.shrink_to_fit();
+                confirmed.expire(now);
+
+                if !display_flags.contains(&'Q') {
+                    let counters =
+                        counters_line(&df_count, &corrected_count, confirmed.unconfirmed);
+                    print_screen(planes, args, &flags, now, &counters);
+                    debug!("{}", planes[&(icao, address_type)]);
+                    timestamp = now;
+                }
             }
-            Err(e) => warn!("Warn: {}", e),
         }
     }
+    if !display_flags.contains(&'Q') {
+        let counters = counters_line(&df_count, &corrected_count, confirmed.unconfirmed);
+        print_screen(planes, args, &flags, clock.now(), &counters);
+    }
     Ok(())
 }
 
+/// Plane table, the aircraft by interrogator and the counters with `-c`.
+fn print_screen(
+    planes: &mut HashMap<(u32, AddressType), Plane>,
+    args: &Args,
+    flags: &DisplayFlags,
+    now: DateTime<Utc>,
+    counters: &str,
+) {
+    clear_screen();
+    print_header(flags, true);
+    print_planes(planes, args, flags, now);
+    print_header(flags, false);
+
+    if flags.radars {
+        print_radars(planes, now);
+    }
+
+    if args.count_df {
+        println!("{}", counters);
+    }
+}
+
+/// DF counts, corrected bits, position rejects and unconfirmed replies.
+fn counters_line(
+    df_count: &BTreeMap<u32, u32>,
+    corrected_count: &BTreeMap<u32, u32>,
+    unconfirmed: u64,
+) -> String {
+    let result = df_count.iter().fold(String::new(), |acc, (df, count)| {
+        acc + &format!("DF{}:{} ", df, count)
+    });
+    let result = corrected_count.iter().fold(result, |acc, (bits, count)| {
+        acc + &format!("FIX{}:{} ", bits, count)
+    });
+    let result = decoder::position_rejects()
+        .iter()
+        .filter(|(_, count)| *count > 0)
+        .fold(result, |acc, (reject, count)| {
+            acc + &format!("{}:{} ", reject, count)
+        });
+    match unconfirmed {
+        0 => result,
+        unconfirmed => result + &format!("UNC:{} ", unconfirmed),
+    }
+}
+
+/// Time, address, callsign, position and altitude of the plane followed by the advisory.
+fn ra_log_line(
+    plane: &Plane,
+    ra: &decoder::ResolutionAdvisory,
+    df: u32,
+    time: DateTime<Utc>,
+) -> String {
+    format!("{},DF{},{}", plane_log_line(plane, time), df, ra)
+}
+
+/// Time, address, callsign, position and altitude of the plane.
+fn plane_log_line(plane: &Plane, time: DateTime<Utc>) -> String {
+    let position = match (plane.lat, plane.lon) {
+        (lat, lon) if lat != 0.0 && lon != 0.0 => format!("{:.5},{:.5}", lat, lon),
+        _ => String::from(","),
+    };
+    format!(
+        "{},{:06X},{},{},{}",
+        time.format("%Y-%m-%d %H:%M:%S%.3f"),
+        plane.icao,
+        plane.ais.as_deref().unwrap_or(""),
+        position,
+        plane.altitude.map(|a| a.to_string()).unwrap_or_default(),
+    )
+}
+
 fn clear_screen() {
     print!("{0}[2J{0}[H{0}[3J", 27 as char);
 }
@@ -1,17 +1,15 @@
 use crate::Args;
-use squitterator::decoder::{format_simple_display, Plane};
+use chrono::{DateTime, Utc};
+use squitterator::decoder::{format_simple_display, AddressType, DisplayFlags, Plane};
 use std::collections::HashMap;
 
 pub(super) fn print_planes(
-    planes: &mut HashMap<u32, Plane>,
+    planes: &mut HashMap<(u32, AddressType), Plane>,
     args: &Args,
-    weather: bool,
-    angles: bool,
-    speed: bool,
-    altitude: bool,
-    extra: bool,
+    flags: &DisplayFlags,
+    now: DateTime<Utc>,
 ) {
-    let mut planes_vector: Vec<(&u32, &Plane)> = planes.iter().collect();
+    let mut planes_vector: Vec<(&(u32, AddressType), &Plane)> = planes.iter().collect();
     planes_vector.sort_by_cached_key(|&(k, _)| k);
     for order_by in &args.order_by {
         for c in order_by.chars() {
@@ -60,10 +58,7 @@ pub(super) fn print_planes(
     print!(
         "{}",
         planes_vector.iter().fold(String::new(), |acc, (_, plane)| {
-            acc + &format!(
-                "{}\n",
-                format_simple_display(*plane, weather, angles, speed, altitude, extra)
-            )
+            acc + &format!("{}\n", format_simple_display(*plane, flags, now))
         })
     );
 }
@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use squitterator::decoder::{AddressType, Interrogator, Plane};
+use std::collections::{BTreeMap, HashMap};
+
+/// Replies older than this no longer count as the aircraft being illuminated.
+const ILLUMINATION_TIMEOUT: i64 = 60;
+
+/// Prints the aircraft replying to each interrogator, followed by the aircraft
+/// seen by their acquisition squitters only, without ADS-B.
+pub(super) fn print_radars(planes: &HashMap<(u32, AddressType), Plane>, now: DateTime<Utc>) {
+    let mut radars: BTreeMap<Interrogator, Vec<u32>> = BTreeMap::new();
+    for plane in planes.values() {
+        for (interrogator, timestamp) in &plane.interrogators {
+            if now.signed_duration_since(*timestamp).num_seconds() < ILLUMINATION_TIMEOUT {
+                radars.entry(*interrogator).or_default().push(plane.icao);
+            }
+        }
+    }
+
+    for (interrogator, icaos) in radars.iter_mut() {
+        icaos.sort_unstable();
+        println!("{} {:3}: {}", interrogator, icaos.len(), icao_list(icaos));
+    }
+
+    let mut acquisition_only = planes
+        .values()
+        .filter(|plane| plane.is_acquisition_only())
+        .map(|plane| plane.icao)
+        .collect::<Vec<u32>>();
+    acquisition_only.sort_unstable();
+    println!(
+        "ACQ  {:3}: {}",
+        acquisition_only.len(),
+        icao_list(&acquisition_only)
+    );
+}
+
+fn icao_list(icaos: &[u32]) -> String {
+    icaos
+        .iter()
+        .map(|icao| format!("{:06X}", icao))
+        .collect::<Vec<String>>()
+        .join(" ")
+}